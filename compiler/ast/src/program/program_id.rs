@@ -17,6 +17,7 @@
 use crate::Identifier;
 
 use core::fmt;
+use leo_span::Symbol;
 use serde::{de, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::BTreeMap;
 
@@ -75,8 +76,9 @@ impl<'de> Deserialize<'de> for ProgramId {
                 // Convert the serialized string into a BTreeMap to recover ProgramId.
                 let key: BTreeMap<String, String> = to_json_string(value)?;
 
+                // Note that the name is serialized as a plain string, so its span and ID are not recovered.
                 let name: Identifier = match key.get("name") {
-                    Some(name) => to_json_string(name)?,
+                    Some(name) => Identifier::new(Symbol::intern(name), Default::default()),
                     None => return Err(E::custom("missing 'name' in serialized ProgramId struct")),
                 };
 
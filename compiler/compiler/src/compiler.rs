@@ -212,6 +212,7 @@ impl<'a> Compiler<'a> {
     pub fn static_single_assignment_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
         self.ast = StaticSingleAssigner::do_pass((
             std::mem::take(&mut self.ast),
+            self.handler,
             &self.node_builder,
             &self.assigner,
             symbol_table,
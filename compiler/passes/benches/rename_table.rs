@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks the `RenameTable` for scopes of various sizes, comparing inline storage against an `IndexMap`.
+
+use leo_passes::RenameTable;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// The number of locals in each benchmarked scope.
+const SCOPE_SIZES: [usize; 3] = [8, 64, 512];
+
+/// The thresholds to compare, where a threshold of zero always uses an `IndexMap`.
+const THRESHOLDS: [usize; 3] = [0, RenameTable::DEFAULT_THRESHOLD, 64];
+
+/// Fills a scope with `names`, renames each of them again, and then looks each of them up.
+fn rename_scope(names: &[(Symbol, Symbol, Symbol)], threshold: usize) -> usize {
+    let mut table = RenameTable::with_threshold(None, threshold);
+    for (id, (name, first, _)) in names.iter().enumerate() {
+        table.update(*name, *first, id);
+    }
+    for (id, (name, _, second)) in names.iter().enumerate() {
+        table.update(*name, *second, id);
+    }
+    names.iter().filter(|(name, ..)| table.lookup(*name).is_some()).count()
+}
+
+fn bench_rename_table(c: &mut Criterion) {
+    create_session_if_not_set_then(|_| {
+        let mut group = c.benchmark_group("rename_table");
+        for size in SCOPE_SIZES {
+            let names: Vec<_> = (0..size)
+                .map(|i| {
+                    (
+                        Symbol::intern(&format!("local{i}")),
+                        Symbol::intern(&format!("local{i}$0")),
+                        Symbol::intern(&format!("local{i}$1")),
+                    )
+                })
+                .collect();
+            for threshold in THRESHOLDS {
+                group.bench_with_input(BenchmarkId::new(format!("threshold_{threshold}"), size), &names, |b, names| {
+                    b.iter(|| rename_scope(black_box(names), threshold))
+                });
+            }
+        }
+        group.finish();
+    })
+}
+
+criterion_group!(benches, bench_rename_table);
+criterion_main!(benches);
@@ -14,44 +14,87 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+mod scope_map;
+use scope_map::ScopeMap;
+
 use leo_ast::NodeID;
 use leo_span::Symbol;
 
-use indexmap::IndexMap;
-
 /// `RenameTable` tracks the names assigned by static single assignment in a single scope.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct RenameTable {
     /// The `RenameTable` of the parent scope.
     pub(crate) parent: Option<Box<RenameTable>>,
     /// The mapping from names in the original AST to new names in the renamed AST.
-    names: IndexMap<Symbol, Symbol>,
+    names: ScopeMap<Symbol, Symbol>,
     /// The mapping from symbols to node IDs.
     /// These are used to ensure that newly introduced symbols reference the appropriate information
     /// that has been previously indexed by node ID. e,g. `TypeTable`.
-    ids: IndexMap<Symbol, NodeID>,
+    ids: ScopeMap<Symbol, NodeID>,
+    /// The number of entries stored inline before a mapping is promoted to an `IndexMap`.
+    threshold: usize,
+}
+
+impl Default for RenameTable {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+/// Two tables are equal if they hold the same entries, regardless of the threshold at which their entries are promoted.
+impl PartialEq for RenameTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.parent == other.parent && self.names == other.names && self.ids == other.ids
+    }
 }
 
+impl Eq for RenameTable {}
+
 impl RenameTable {
+    /// The default number of entries stored inline before a mapping is promoted to an `IndexMap`.
+    /// Most scopes are small, for which a linear scan is faster than hashing.
+    /// Note that this is public only for the benchmarks.
+    #[doc(hidden)]
+    pub const DEFAULT_THRESHOLD: usize = 16;
+
     /// Create a new `RenameTable` with the given parent.
     pub(crate) fn new(parent: Option<Box<RenameTable>>) -> Self {
-        Self { parent, names: IndexMap::new(), ids: IndexMap::new() }
+        Self::with_threshold(parent, Self::DEFAULT_THRESHOLD)
+    }
+
+    /// Create a new `RenameTable` with the given parent, which stores up to `threshold` entries inline.
+    /// Note that this is public only for the benchmarks.
+    #[doc(hidden)]
+    pub fn with_threshold(parent: Option<Box<RenameTable>>, threshold: usize) -> Self {
+        Self { parent, names: ScopeMap::default(), ids: ScopeMap::default(), threshold }
+    }
+
+    /// Returns the number of entries stored inline before a mapping is promoted to an `IndexMap`.
+    pub(crate) fn threshold(&self) -> usize {
+        self.threshold
     }
 
     /// Returns the symbols that were renamed in the current scope.
     pub(crate) fn local_names(&self) -> impl Iterator<Item = &Symbol> {
-        self.names.keys()
+        self.names.iter().map(|(name, _)| name)
+    }
+
+    /// Returns the mappings from original names to new names in the current scope.
+    pub fn mappings(&self) -> impl Iterator<Item = (&Symbol, &Symbol)> {
+        self.names.iter()
     }
 
     /// Updates `self.mapping` with the desired entry.
     /// Creates a new entry if `symbol` is not already in `self.mapping`.
-    pub(crate) fn update(&mut self, symbol: Symbol, new_symbol: Symbol, id: NodeID) {
-        self.names.insert(symbol, new_symbol);
-        self.ids.insert(new_symbol, id);
+    /// Note that this is public only for the benchmarks.
+    #[doc(hidden)]
+    pub fn update(&mut self, symbol: Symbol, new_symbol: Symbol, id: NodeID) {
+        self.names.insert(symbol, new_symbol, self.threshold);
+        self.ids.insert(new_symbol, id, self.threshold);
     }
 
     /// Looks up the new name for `symbol`, recursively checking the parent if it is not found.
-    pub(crate) fn lookup(&self, symbol: Symbol) -> Option<&Symbol> {
+    pub fn lookup(&self, symbol: Symbol) -> Option<&Symbol> {
         if let Some(var) = self.names.get(&symbol) {
             Some(var)
         } else if let Some(parent) = &self.parent {
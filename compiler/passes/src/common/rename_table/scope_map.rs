@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::IndexMap;
+use std::hash::Hash;
+
+/// An insertion-ordered map for the entries of a single scope.
+/// Small scopes store their entries inline in a vector, which is promoted to an `IndexMap` once it exceeds a threshold.
+#[derive(Clone, Debug)]
+pub(crate) enum ScopeMap<K, V> {
+    /// A scope with at most `threshold` entries.
+    Small(Vec<(K, V)>),
+    /// A scope with more than `threshold` entries.
+    Large(IndexMap<K, V>),
+}
+
+impl<K, V> Default for ScopeMap<K, V> {
+    fn default() -> Self {
+        Self::Small(Vec::new())
+    }
+}
+
+impl<K: Copy + Eq + Hash, V> ScopeMap<K, V> {
+    /// Inserts the entry, replacing the value of an existing entry in place.
+    /// Promotes the map to an `IndexMap` if it then holds more than `threshold` entries.
+    pub(crate) fn insert(&mut self, key: K, value: V, threshold: usize) {
+        match self {
+            Self::Small(entries) => {
+                match entries.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, v)) => *v = value,
+                    None => entries.push((key, value)),
+                }
+                if entries.len() > threshold {
+                    *self = Self::Large(core::mem::take(entries).into_iter().collect());
+                }
+            }
+            Self::Large(map) => {
+                map.insert(key, value);
+            }
+        }
+    }
+
+    /// Returns the value of `key`, if it exists.
+    pub(crate) fn get(&self, key: &K) -> Option<&V> {
+        match self {
+            Self::Small(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            Self::Large(map) => map.get(key),
+        }
+    }
+
+    /// Returns an iterator over the entries, in insertion order.
+    pub(crate) fn iter(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        match self {
+            Self::Small(entries) => Box::new(entries.iter().map(|(k, v)| (k, v))),
+            Self::Large(map) => Box::new(map.iter()),
+        }
+    }
+
+    /// Returns the number of entries.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Small(entries) => entries.len(),
+            Self::Large(map) => map.len(),
+        }
+    }
+}
+
+/// Two maps are equal if they hold the same entries, regardless of their representation or order.
+impl<K: Copy + Eq + Hash, V: PartialEq> PartialEq for ScopeMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Copy + Eq + Hash, V: Eq> Eq for ScopeMap<K, V> {}
@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Program;
+
+use std::path::Path;
+
+/// Serializes `program` to a compact binary format, e.g. to cache the output of the pass, which is faster to reload than JSON.
+///
+/// Note that symbols are serialized as their strings, and are interned again when the program is deserialized,
+/// so the program may be deserialized in another session, in which its symbols have other indices.
+/// However, the spans refer to the source map of the session in which the program was parsed,
+/// and the node IDs may collide with those of a fresh `NodeBuilder`, so they should only be used to correlate nodes within the program.
+/// A `ProgramId` serializes the name of its program as a plain string, so the span and node ID of the name are not recovered.
+/// The `TypeTable` is not part of the program, so it is not serialized.
+pub fn program_to_binary(program: &Program) -> bincode::Result<Vec<u8>> {
+    bincode::serialize(program)
+}
+
+/// Deserializes a program serialized by `program_to_binary`, interning its symbols in the current session.
+pub fn program_from_binary(bytes: &[u8]) -> bincode::Result<Program> {
+    bincode::deserialize(bytes)
+}
+
+/// Writes `program` to the file at `path` in the format of `program_to_binary`.
+pub fn write_program_binary(program: &Program, path: &Path) -> bincode::Result<()> {
+    Ok(std::fs::write(path, program_to_binary(program)?)?)
+}
+
+/// Reads a program from the file at `path` in the format of `program_to_binary`.
+/// Note that the file is read in full before it is deserialized, since the symbols are deserialized from borrowed strings.
+pub fn read_program_binary(path: &Path) -> bincode::Result<Program> {
+    program_from_binary(&std::fs::read(path)?)
+}
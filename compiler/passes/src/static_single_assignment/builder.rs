@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Assigner,
+    CollisionChecker,
+    NumberingScheme,
+    RecordLayout,
+    RenameHook,
+    RenameTransform,
+    ScopeStack,
+    SsaObserver,
+    SsaOptions,
+    StaticSingleAssigner,
+    StructRewriter,
+    SymbolResolver,
+    SymbolTable,
+    TypeTable,
+};
+
+use leo_ast::NodeBuilder;
+use leo_errors::emitter::Handler;
+
+use std::time::Instant;
+
+/// Builds a `StaticSingleAssigner`, configuring its options and observer fluently.
+/// Each option left unset keeps its default, so `StaticSingleAssignerBuilder::new(..).build()` behaves like `StaticSingleAssigner::new`
+/// with `SsaOptions::default()`. Options without a dedicated method can be set with `with_options`.
+pub struct StaticSingleAssignerBuilder<'a> {
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+    /// The `SymbolTable` of the program.
+    symbol_table: &'a SymbolTable,
+    /// A mapping from node IDs to their types.
+    type_table: &'a TypeTable,
+    /// A struct used to construct (unique) assignment statements.
+    assigner: &'a Assigner,
+    /// The handler through which diagnostics are reported.
+    handler: &'a Handler,
+    /// The options of the pass.
+    options: SsaOptions,
+    /// The observer notified as each function is consumed, if one is set.
+    observer: Option<Box<dyn SsaObserver + 'a>>,
+}
+
+impl<'a> StaticSingleAssignerBuilder<'a> {
+    /// Returns a new `StaticSingleAssignerBuilder` over the state produced by the preceding passes.
+    pub fn new(
+        node_builder: &'a NodeBuilder,
+        symbol_table: &'a SymbolTable,
+        type_table: &'a TypeTable,
+        assigner: &'a Assigner,
+        handler: &'a Handler,
+    ) -> Self {
+        Self {
+            node_builder,
+            symbol_table,
+            type_table,
+            assigner,
+            handler,
+            options: SsaOptions::default(),
+            observer: None,
+        }
+    }
+
+    /// Replaces all options with `options`, e.g. before overriding some of them with the other methods.
+    pub fn with_options(self, options: SsaOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    /// Sets the layout used to order the members of records.
+    pub fn with_record_layout(mut self, layout: RecordLayout) -> Self {
+        self.options.record_layout = layout;
+        self
+    }
+
+    /// Sets whether to report each record whose fields are not in the order given by the layout.
+    pub fn with_strict_records(mut self, strict: bool) -> Self {
+        self.options.strict_records = strict;
+        self
+    }
+
+    /// Sets the scheme used to number the new names.
+    pub fn with_numbering(mut self, numbering: NumberingScheme) -> Self {
+        self.options.numbering = numbering;
+        self
+    }
+
+    /// Sets the transformation applied to each new name.
+    pub fn with_rename_transform(mut self, transform: RenameTransform) -> Self {
+        self.options.rename_transform = Some(transform);
+        self
+    }
+
+    /// Sets the hook deciding the new name of each variable.
+    pub fn with_rename_hook(mut self, hook: RenameHook) -> Self {
+        self.options.rename_hook = Some(hook);
+        self
+    }
+
+    /// Sets the check of each new name of a variable before it is committed.
+    pub fn with_collision_checker(mut self, checker: CollisionChecker) -> Self {
+        self.options.collision_checker = Some(checker);
+        self
+    }
+
+    /// Sets the rewriting applied to each struct before its members are ordered.
+    pub fn with_struct_rewriter(mut self, rewriter: StructRewriter) -> Self {
+        self.options.struct_rewriter = Some(rewriter);
+        self
+    }
+
+    /// Sets the resolver used to render the symbols in diagnostics.
+    pub fn with_symbol_resolver(mut self, resolver: SymbolResolver) -> Self {
+        self.options.symbol_resolver = Some(resolver);
+        self
+    }
+
+    /// Sets the maximum number of temporaries the pass may introduce in a program.
+    pub fn with_max_temporaries(mut self, max: usize) -> Self {
+        self.options.max_temporaries = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of functions in a program and its imports.
+    pub fn with_max_functions(mut self, max: usize) -> Self {
+        self.options.max_functions = Some(max);
+        self
+    }
+
+    /// Sets the instant after which the remaining functions are not consumed.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.options.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the observer notified as each function is consumed.
+    pub fn with_observer(mut self, observer: impl SsaObserver + 'a) -> Self {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Builds a `StaticSingleAssigner` that tracks the new names of variables with the default `RenameTable`.
+    pub fn build(self) -> StaticSingleAssigner<'a> {
+        let mut assigner = StaticSingleAssigner::new(
+            self.node_builder,
+            self.symbol_table,
+            self.type_table,
+            self.assigner,
+            self.handler,
+            self.options,
+        );
+        assigner.observer = self.observer;
+        assigner
+    }
+
+    /// Builds a `StaticSingleAssigner` that tracks the new names of variables with `scope_stack`.
+    pub fn build_with_scope_stack<S: ScopeStack>(self, scope_stack: S) -> StaticSingleAssigner<'a, S> {
+        let mut assigner = StaticSingleAssigner::with_scope_stack(
+            self.node_builder,
+            self.symbol_table,
+            self.type_table,
+            self.assigner,
+            self.handler,
+            self.options,
+            scope_stack,
+        );
+        assigner.observer = self.observer;
+        assigner
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Function, Program};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+/// Returns a SHA256 checksum of `program`, e.g. to check that builds of the same input on different machines agree.
+/// The checksum is computed over a canonical serialization of the program, in which object keys are sorted,
+/// and the spans and node IDs are removed, since they depend on the order in which sources and nodes are loaded.
+pub fn checksum(program: &Program) -> String {
+    digest(program)
+}
+
+/// Returns a SHA256 checksum of `function`, computed like `checksum`, e.g. to key a cache of the functions of a program.
+/// Note that the checksum does not depend on the program declaring the function, or on the other functions of the program.
+pub fn function_checksum(function: &Function) -> String {
+    digest(function)
+}
+
+/// The checksums of a function before and after it is consumed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionChecksums {
+    /// The checksum of the function in source form, e.g. to key a cache of its SSA form.
+    pub before: String,
+    /// The checksum of the function in SSA form, e.g. to validate a cached SSA form.
+    pub after: String,
+}
+
+/// Returns a SHA256 checksum of the canonical serialization of `value`.
+fn digest(value: &impl Serialize) -> String {
+    let value = serde_json::to_value(value).expect("An AST node should serialize to JSON.");
+    let canonical = serde_json::to_string(&canonicalize(value)).expect("A JSON value should serialize to a string.");
+
+    let mut hasher = Sha256::new();
+    hasher.update(canonical.as_bytes());
+    let hash = hasher.finalize();
+
+    format!("{hash:x}")
+}
+
+/// Returns `value` with the keys of each object sorted and the `span` and `id` keys removed.
+/// Note that identifiers are serialized as JSON strings that embed their span and ID, so they are reduced to their names.
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map
+                .into_iter()
+                .filter(|(key, _)| key != "span" && key != "id")
+                .map(|(key, value)| (canonical_key(key), canonicalize(value)))
+                .collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter().collect::<Map<_, _>>())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        Value::String(string) => Value::String(canonical_key(string)),
+        _ => value,
+    }
+}
+
+/// Returns the name of the identifier serialized as `string`, or `string` itself if it is not an identifier.
+fn canonical_key(string: String) -> String {
+    match serde_json::from_str::<Map<String, Value>>(&string) {
+        Ok(identifier) => match identifier.get("name") {
+            Some(Value::String(name)) => name.clone(),
+            _ => string,
+        },
+        Err(_) => string,
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use leo_ast::{Block, Expression, Program, Statement, Variant};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use std::fmt::Write;
+
+/// Renders a program in SSA form with its blocks laid out by control flow, e.g. to debug how the branches of a conditional are merged.
+/// Each block is annotated with its scope depth, where the block of a function is at depth one, and with the renamings it introduces.
+pub(crate) struct ControlFlowDump<'a> {
+    /// A mapping from the new names of variables to their original names.
+    origins: &'a IndexMap<Symbol, Symbol>,
+    /// The rendered program.
+    pub(crate) output: String,
+}
+
+impl<'a> ControlFlowDump<'a> {
+    pub(crate) fn new(origins: &'a IndexMap<Symbol, Symbol>) -> Self {
+        Self { origins, output: String::new() }
+    }
+
+    /// Renders the program scopes of the imports of `program`, in order, followed by its own program scopes.
+    pub(crate) fn dump_program(&mut self, program: &Program) {
+        for (import, _) in program.imports.values() {
+            self.dump_program(import);
+        }
+        for scope in program.program_scopes.values() {
+            self.line(0, format!("program {}:", scope.program_id));
+            for (_, function) in scope.functions.iter() {
+                let variant = match function.variant {
+                    Variant::Inline => "inline",
+                    Variant::Standard => "function",
+                    Variant::Transition => "transition",
+                };
+                self.line(1, format!("{variant} {}:", function.identifier));
+                self.dump_block(2, 1, "block", &function.block);
+                if let Some(finalize) = &function.finalize {
+                    self.line(1, format!("finalize {}:", finalize.identifier));
+                    self.dump_block(2, 1, "block", &finalize.block);
+                }
+            }
+        }
+    }
+
+    /// Renders `block`, labelled `label`, at `indent`, followed by its statements.
+    fn dump_block(&mut self, indent: usize, depth: usize, label: &str, block: &Block) {
+        let renamings: Vec<String> = block
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::Assign(assign) => match &assign.place {
+                    Expression::Identifier(identifier) => match self.origins.get(&identifier.name) {
+                        // Inputs resolve to themselves, so they are not renamings.
+                        Some(original) if *original != identifier.name => Some(format!("{original} -> {identifier}")),
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        match renamings.is_empty() {
+            true => self.line(indent, format!("{label} at depth {depth}")),
+            false => self.line(indent, format!("{label} at depth {depth}: {}", renamings.join(", "))),
+        }
+        for statement in block.statements.iter() {
+            self.dump_statement(indent + 1, depth, statement);
+        }
+    }
+
+    fn dump_statement(&mut self, indent: usize, depth: usize, statement: &Statement) {
+        match statement {
+            Statement::Block(block) => self.dump_block(indent, depth + 1, "block", block),
+            Statement::Conditional(conditional) => {
+                self.line(indent, format!("if {}", conditional.condition));
+                self.dump_block(indent + 1, depth + 1, "then block", &conditional.then);
+                match conditional.otherwise.as_deref() {
+                    Some(Statement::Block(block)) => self.dump_block(indent + 1, depth + 1, "else block", block),
+                    Some(otherwise) => self.dump_statement(indent + 1, depth, otherwise),
+                    None => {}
+                }
+            }
+            _ => self.line(indent, statement.to_string()),
+        }
+    }
+
+    fn line(&mut self, indent: usize, line: String) {
+        writeln!(self.output, "{:width$}{line}", "", width = indent * 2).unwrap();
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    Block,
+    ConditionalStatement,
+    Expression,
+    ExpressionVisitor,
+    IterationStatement,
+    Statement,
+    StatementVisitor,
+    StructExpression,
+};
+
+use indexmap::IndexMap;
+
+/// The number of statements and expressions of each kind consumed by the pass, e.g. to check that the test fixtures exercise every kind.
+/// The kinds are keyed by the names of the variants of `Statement` and `Expression`, e.g. `Definition` or `Binary`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeCoverage {
+    /// The number of statements of each kind, in the order in which the kinds are first consumed.
+    pub statements: IndexMap<&'static str, usize>,
+    /// The number of expressions of each kind, in the order in which the kinds are first consumed.
+    pub expressions: IndexMap<&'static str, usize>,
+}
+
+impl NodeCoverage {
+    /// The names of the kinds of expressions, except `Err`, which does not occur in a type checked program.
+    pub const EXPRESSION_KINDS: [&'static str; 12] = [
+        "Access",
+        "Array",
+        "Binary",
+        "Call",
+        "Cast",
+        "Struct",
+        "Identifier",
+        "Literal",
+        "Ternary",
+        "Tuple",
+        "Unary",
+        "Unit",
+    ];
+    /// The names of the kinds of statements.
+    pub const STATEMENT_KINDS: [&'static str; 10] = [
+        "Assert",
+        "Assign",
+        "Block",
+        "Conditional",
+        "Console",
+        "Const",
+        "Definition",
+        "Expression",
+        "Iteration",
+        "Return",
+    ];
+
+    /// Returns the kinds of statements that were not consumed, in the order of `STATEMENT_KINDS`.
+    pub fn uncovered_statements(&self) -> Vec<&'static str> {
+        Self::STATEMENT_KINDS.into_iter().filter(|kind| !self.statements.contains_key(kind)).collect()
+    }
+
+    /// Returns the kinds of expressions that were not consumed, in the order of `EXPRESSION_KINDS`.
+    pub fn uncovered_expressions(&self) -> Vec<&'static str> {
+        Self::EXPRESSION_KINDS.into_iter().filter(|kind| !self.expressions.contains_key(kind)).collect()
+    }
+}
+
+/// Tallies the kinds of a statement and its expressions into a `NodeCoverage`.
+/// Note that nested blocks are not visited, since each block is tallied when it is consumed,
+/// but a conditional statement in the otherwise-block is, since it is consumed without a block.
+pub(crate) struct CoverageCounter<'c>(pub(crate) &'c mut NodeCoverage);
+
+impl<'a> ExpressionVisitor<'a> for CoverageCounter<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        let kind = match input {
+            Expression::Access(_) => "Access",
+            Expression::Array(_) => "Array",
+            Expression::Binary(_) => "Binary",
+            Expression::Call(_) => "Call",
+            Expression::Cast(_) => "Cast",
+            Expression::Struct(_) => "Struct",
+            Expression::Err(_) => "Err",
+            Expression::Identifier(_) => "Identifier",
+            Expression::Literal(_) => "Literal",
+            Expression::Ternary(_) => "Ternary",
+            Expression::Tuple(_) => "Tuple",
+            Expression::Unary(_) => "Unary",
+            Expression::Unit(_) => "Unit",
+        };
+        *self.0.expressions.entry(kind).or_default() += 1;
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Err(_) | Expression::Identifier(_) | Expression::Literal(_) | Expression::Unit(_) => {}
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for expression in input.members.iter().filter_map(|member| member.expression.as_ref()) {
+            self.visit_expression(expression, additional);
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for CoverageCounter<'_> {
+    fn visit_statement(&mut self, input: &'a Statement) {
+        let kind = match input {
+            Statement::Assert(_) => "Assert",
+            Statement::Assign(_) => "Assign",
+            Statement::Block(_) => "Block",
+            Statement::Conditional(_) => "Conditional",
+            Statement::Console(_) => "Console",
+            Statement::Const(_) => "Const",
+            Statement::Definition(_) => "Definition",
+            Statement::Expression(_) => "Expression",
+            Statement::Iteration(_) => "Iteration",
+            Statement::Return(_) => "Return",
+        };
+        *self.0.statements.entry(kind).or_default() += 1;
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+    }
+
+    fn visit_block(&mut self, _input: &'a Block) {}
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.visit_expression(&input.condition, &());
+        if let Some(otherwise @ Statement::Conditional(_)) = input.otherwise.as_deref() {
+            self.visit_statement(otherwise);
+        }
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+    }
+}
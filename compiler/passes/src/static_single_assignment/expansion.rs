@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Block, Program, Statement};
+
+/// The typical ratio of output to input statements, used to estimate the size of the output of the pass.
+/// Most statements introduce a temporary for each of their subexpressions, and each conditional introduces phi functions.
+pub const DEFAULT_EXPANSION_FACTOR: f64 = 3.0;
+
+/// The number of statements in the block of a function before and after the pass.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StatementExpansion {
+    /// The number of statements before the pass, including those in nested blocks.
+    pub input: usize,
+    /// The number of statements after the pass, including those in nested blocks.
+    pub output: usize,
+}
+
+impl StatementExpansion {
+    /// Returns the ratio of output statements to input statements.
+    /// An empty block has a ratio of one, since the pass leaves it unchanged.
+    pub fn ratio(&self) -> f64 {
+        match self.input {
+            0 => 1.0,
+            input => self.output as f64 / input as f64,
+        }
+    }
+}
+
+/// Returns the number of statements in the function and finalize blocks of `program` and its imports.
+pub(crate) fn count_program_statements(program: &Program) -> usize {
+    let mut count = 0;
+    let mut programs = vec![program];
+    while let Some(program) = programs.pop() {
+        programs.extend(program.imports.values().map(|(import, _)| import));
+        for scope in program.program_scopes.values() {
+            for (_, function) in scope.functions.iter() {
+                count += count_statements(&function.block);
+                count += function.finalize.as_ref().map_or(0, |finalize| count_statements(&finalize.block));
+            }
+        }
+    }
+    count
+}
+
+/// Returns the number of statements in `block`, including those in nested blocks.
+pub(crate) fn count_statements(block: &Block) -> usize {
+    block.statements.iter().map(count_statement).sum()
+}
+
+/// Returns the number of statements in `statement`, including itself and those in nested blocks.
+fn count_statement(statement: &Statement) -> usize {
+    1 + match statement {
+        Statement::Block(block) => count_statements(block),
+        Statement::Conditional(conditional) => {
+            count_statements(&conditional.then) + conditional.otherwise.as_deref().map_or(0, count_statement)
+        }
+        Statement::Iteration(iteration) => count_statements(&iteration.block),
+        _ => 0,
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ProgramName;
+
+use leo_span::Symbol;
+
+use std::fmt::{Display, Write};
+
+/// A single renaming introduced by the pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RenameRecord {
+    /// The program declaring the function.
+    pub program: ProgramName,
+    /// The name of the function whose block, or finalize block, introduced the renaming.
+    pub function: Symbol,
+    /// The original name of the variable.
+    pub original: Symbol,
+    /// The new name of the variable.
+    pub renamed: Symbol,
+    /// The depth of the scope in which the renaming was introduced, where the block of the function is at depth one.
+    pub scope_depth: usize,
+}
+
+/// Returns `records` as CSV, with a header row followed by one row per record.
+/// Fields containing a comma, a quote, or a line break are quoted, doubling any quotes they contain.
+pub fn rename_history_to_csv(records: &[RenameRecord]) -> String {
+    let mut csv = String::from("program,function,original,renamed,scope_depth\n");
+    for record in records {
+        // Writing to a `String` cannot fail.
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{}",
+            escape(record.program),
+            escape(record.function),
+            escape(record.original),
+            escape(record.renamed),
+            record.scope_depth
+        );
+    }
+    csv
+}
+
+/// Returns `value` as a CSV field.
+fn escape(value: impl Display) -> String {
+    let field = value.to_string();
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field,
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+use std::fmt::Write;
+
+/// An import relation between two programs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Import {
+    /// The name of the importing program.
+    pub from: Symbol,
+    /// The name of the imported program.
+    pub to: Symbol,
+    /// Whether the imported program is already being consumed, i.e. the import closes a cycle.
+    pub is_cycle: bool,
+}
+
+/// The tree of imports traversed while consuming a program.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SsaImportTree {
+    /// The names of the programs, in the order in which they are traversed.
+    programs: IndexSet<Symbol>,
+    /// The import relations, in the order in which they are traversed.
+    imports: IndexSet<Import>,
+}
+
+impl SsaImportTree {
+    /// Returns the names of the programs in the graph.
+    pub fn programs(&self) -> &IndexSet<Symbol> {
+        &self.programs
+    }
+
+    /// Returns the import relations in the graph.
+    pub fn imports(&self) -> &IndexSet<Import> {
+        &self.imports
+    }
+
+    /// Adds the program named `program` to the graph.
+    pub(crate) fn add_program(&mut self, program: Symbol) {
+        self.programs.insert(program);
+    }
+
+    /// Adds the import of `to` by `from` to the graph.
+    pub(crate) fn add_import(&mut self, from: Symbol, to: Symbol, is_cycle: bool) {
+        self.add_program(to);
+        self.imports.insert(Import { from, to, is_cycle });
+    }
+
+    /// Returns the names of `program` and of the programs that import it, directly or transitively, in the order in which they are found.
+    /// These are the programs whose output may change when `program` changes.
+    pub fn dependents(&self, program: Symbol) -> IndexSet<Symbol> {
+        let mut dependents = IndexSet::from([program]);
+        let mut index = 0;
+        while let Some(dependency) = dependents.get_index(index).copied() {
+            dependents.extend(self.imports.iter().filter(|import| import.to == dependency).map(|import| import.from));
+            index += 1;
+        }
+        dependents
+    }
+
+    /// Returns the Graphviz DOT representation of the graph.
+    /// Imports that close a cycle are drawn as dashed red edges.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph imports {\n");
+        for program in &self.programs {
+            writeln!(dot, "    \"{program}\";").unwrap();
+        }
+        for import in &self.imports {
+            match import.is_cycle {
+                false => writeln!(dot, "    \"{}\" -> \"{}\";", import.from, import.to).unwrap(),
+                true => writeln!(dot, "    \"{}\" -> \"{}\" [style = dashed, color = red];", import.from, import.to)
+                    .unwrap(),
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
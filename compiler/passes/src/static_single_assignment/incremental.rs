@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use leo_ast::ProgramScope;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// The output of the pass for each program scope, keyed by the name of its program, e.g. to recompile only the changed scopes in a watch mode.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScopeCache {
+    scopes: IndexMap<Symbol, ProgramScope>,
+}
+
+impl ScopeCache {
+    /// Returns the cached output for the program scope of the program named `program`.
+    pub fn get(&self, program: Symbol) -> Option<&ProgramScope> {
+        self.scopes.get(&program)
+    }
+
+    /// Caches `scope` as the output for the program scope of the program named `program`.
+    pub(crate) fn insert(&mut self, program: Symbol, scope: ProgramScope) {
+        self.scopes.insert(program, scope);
+    }
+
+    /// Removes the cached output for the program scope of the program named `program`, returning it if it was cached.
+    pub fn invalidate(&mut self, program: Symbol) -> Option<ProgramScope> {
+        self.scopes.shift_remove(&program)
+    }
+
+    /// Returns the number of cached program scopes.
+    pub fn len(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Returns `true` if no program scopes are cached.
+    pub fn is_empty(&self) -> bool {
+        self.scopes.is_empty()
+    }
+}
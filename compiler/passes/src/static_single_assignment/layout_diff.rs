@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::QualifiedRecord;
+
+use leo_ast::Struct;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt;
+
+/// A difference between the layout of a record in a baseline and its current layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayoutChange {
+    /// The record is not in the baseline.
+    RecordAdded { record: QualifiedRecord },
+    /// The record is in the baseline, but no longer declared.
+    RecordRemoved { record: QualifiedRecord },
+    /// The record declares a field that is not in the baseline.
+    FieldAdded { record: QualifiedRecord, field: Symbol },
+    /// The record no longer declares a field in the baseline.
+    FieldRemoved { record: QualifiedRecord, field: Symbol },
+    /// The fields declared both in the baseline and currently are in a different order.
+    FieldsReordered { record: QualifiedRecord, baseline: Vec<Symbol>, current: Vec<Symbol> },
+}
+
+impl fmt::Display for LayoutChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let join = |fields: &[Symbol]| fields.iter().map(|field| field.to_string()).collect::<Vec<_>>().join(", ");
+        match self {
+            Self::RecordAdded { record } => write!(f, "{record}: added"),
+            Self::RecordRemoved { record } => write!(f, "{record}: removed"),
+            Self::FieldAdded { record, field } => write!(f, "{record}: field `{field}` added"),
+            Self::FieldRemoved { record, field } => write!(f, "{record}: field `{field}` removed"),
+            Self::FieldsReordered { record, baseline, current } => {
+                write!(f, "{record}: fields reordered from [{}] to [{}]", join(baseline), join(current))
+            }
+        }
+    }
+}
+
+/// Returns the layout of each of `records`, e.g. those returned by `StaticSingleAssigner::consume_records`, as the names of its fields.
+/// The layouts can be saved as a baseline for `diff_record_layouts`.
+pub fn record_layouts(records: &IndexMap<QualifiedRecord, Struct>) -> IndexMap<QualifiedRecord, Vec<Symbol>> {
+    records
+        .iter()
+        .map(|(record, struct_)| (*record, struct_.members.iter().map(|member| member.name()).collect()))
+        .collect()
+}
+
+/// Returns the differences between the `baseline` layouts of records and their `current` layouts, e.g. to check ABI stability in CI.
+/// The changes to the records of the baseline come first, in order, followed by the records that are not in the baseline.
+/// A record whose layout is unchanged contributes nothing, and the order of its fields is only compared over the fields that are in both layouts.
+pub fn diff_record_layouts(
+    baseline: &IndexMap<QualifiedRecord, Vec<Symbol>>,
+    current: &IndexMap<QualifiedRecord, Vec<Symbol>>,
+) -> Vec<LayoutChange> {
+    let mut changes = Vec::new();
+    for (record, baseline_fields) in baseline.iter() {
+        let record = *record;
+        let current_fields = match current.get(&record) {
+            Some(fields) => fields,
+            None => {
+                changes.push(LayoutChange::RecordRemoved { record });
+                continue;
+            }
+        };
+        let (before, after): (IndexSet<&Symbol>, IndexSet<&Symbol>) =
+            (baseline_fields.iter().collect(), current_fields.iter().collect());
+        changes.extend(before.difference(&after).map(|field| LayoutChange::FieldRemoved { record, field: **field }));
+        changes.extend(after.difference(&before).map(|field| LayoutChange::FieldAdded { record, field: **field }));
+
+        let kept = |fields: &[Symbol], other: &IndexSet<&Symbol>| -> Vec<Symbol> {
+            fields.iter().filter(|field| other.contains(field)).copied().collect()
+        };
+        let (baseline_order, current_order) = (kept(baseline_fields, &after), kept(current_fields, &before));
+        if baseline_order != current_order {
+            changes.push(LayoutChange::FieldsReordered { record, baseline: baseline_order, current: current_order });
+        }
+    }
+    changes.extend(
+        current
+            .keys()
+            .filter(|record| !baseline.contains_key(*record))
+            .map(|record| LayoutChange::RecordAdded { record: *record }),
+    );
+    changes
+}
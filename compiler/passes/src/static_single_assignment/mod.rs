@@ -49,6 +49,63 @@
 //! }
 //! ```
 //! Note that the redundant assignments have no effect on the bytecode generated by the compiler.
+//!
+//! The new names depend only on the order of the traversal, and the pass only iterates over insertion-ordered collections.
+//! Consequently, consuming identical programs with fresh `Assigner`s produces identical output.
+
+pub mod binary;
+pub use binary::*;
+
+pub mod builder;
+pub use builder::*;
+
+pub mod checksum;
+pub use checksum::*;
+
+mod control_flow;
+pub(crate) use control_flow::*;
+
+pub mod coverage;
+pub use coverage::*;
+
+pub mod expansion;
+pub use expansion::*;
+
+pub mod history;
+pub use history::*;
+
+pub mod import_graph;
+pub use import_graph::*;
+
+pub mod incremental;
+pub use incremental::*;
+
+pub mod layout_diff;
+pub use layout_diff::*;
+
+mod name_collector;
+pub(crate) use name_collector::*;
+
+mod nesting;
+pub(crate) use nesting::*;
+
+pub mod mutation;
+pub use mutation::*;
+
+pub mod observer;
+pub use observer::*;
+
+pub mod options;
+pub use options::*;
+
+pub mod progress;
+pub use progress::*;
+
+pub mod qualified_name;
+pub use qualified_name::*;
+
+pub mod record_layout;
+pub use record_layout::*;
 
 mod rename_expression;
 
@@ -56,21 +113,50 @@ mod rename_program;
 
 mod rename_statement;
 
+mod struct_references;
+pub(crate) use struct_references::*;
+
+pub mod scope_stack;
+pub use scope_stack::*;
+
+pub mod sexpr;
+pub use sexpr::*;
+
+pub mod signature;
+pub use signature::*;
+
+mod state;
+pub(crate) use state::*;
+
 pub mod static_single_assigner;
 pub use static_single_assigner::*;
 
+pub mod summary;
+pub use summary::*;
+
+pub mod timeline;
+pub use timeline::*;
+
+pub mod validator;
+pub use validator::*;
+
+#[cfg(test)]
+mod tests;
+
 use crate::{Assigner, Pass, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramConsumer};
-use leo_errors::Result;
+use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for StaticSingleAssigner<'a> {
-    type Input = (Ast, &'a NodeBuilder, &'a Assigner, &'a SymbolTable, &'a TypeTable);
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a Assigner, &'a SymbolTable, &'a TypeTable);
     type Output = Result<Ast>;
 
-    fn do_pass((ast, node_builder, assigner, symbol_table, type_table): Self::Input) -> Self::Output {
-        let mut consumer = StaticSingleAssigner::new(node_builder, symbol_table, type_table, assigner);
+    fn do_pass((ast, handler, node_builder, assigner, symbol_table, type_table): Self::Input) -> Self::Output {
+        let mut consumer =
+            StaticSingleAssigner::new(node_builder, symbol_table, type_table, assigner, handler, SsaOptions::default());
         let program = consumer.consume_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
 
         Ok(Ast::new(program))
     }
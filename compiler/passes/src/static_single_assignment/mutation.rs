@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    AssignStatement,
+    Block,
+    ConditionalStatement,
+    Expression,
+    ExpressionVisitor,
+    Function,
+    Identifier,
+    Input,
+    NodeID,
+    ProgramVisitor,
+    StatementVisitor,
+    StructExpression,
+};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use std::fmt;
+
+/// A point in a program in SSA form at which a mutant could be injected, e.g. to check that a test suite catches a faulty renaming.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MutationPoint {
+    /// The function containing the point. Note that points in a finalize block are attributed to its function.
+    pub function: Symbol,
+    /// The ID of the node to mutate.
+    pub id: NodeID,
+    /// The mutation that could be injected.
+    pub kind: MutationKind,
+}
+
+/// A mutation that could be injected into a program in SSA form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MutationKind {
+    /// The use of `name` could be replaced by `alternative`, an earlier version of the same variable that is still in scope.
+    SwapVersion { name: Symbol, alternative: Symbol },
+    /// The arguments of a phi function, which selects between two versions of `variable`, could be swapped.
+    /// Note that the pass stores the selection in a temporary before assigning it, e.g. `$var$6 = c ? b$4 : b$2; b$5 = $var$6;`.
+    SwapPhiArguments { variable: Symbol },
+}
+
+impl fmt::Display for MutationPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            MutationKind::SwapVersion { name, alternative } => {
+                write!(f, "{}: replace `{name}` with `{alternative}`", self.function)
+            }
+            MutationKind::SwapPhiArguments { variable } => {
+                write!(f, "{}: swap the arguments of the phi for `{variable}`", self.function)
+            }
+        }
+    }
+}
+
+/// A read-only traversal that enumerates the mutation points of a program in SSA form.
+/// The versions of each variable are found with `origins`, the mapping from each new name to the original name it renames.
+pub(crate) struct MutationFinder<'a> {
+    /// A mapping from each new name to the original name it renames.
+    origins: &'a IndexMap<Symbol, Symbol>,
+    /// The name of the function currently being visited.
+    function: Symbol,
+    /// The versions of each original variable that are in scope, in order of definition.
+    versions: IndexMap<Symbol, Vec<Symbol>>,
+    /// The mutation points found thus far.
+    pub(crate) points: Vec<MutationPoint>,
+}
+
+impl<'a> MutationFinder<'a> {
+    /// Returns a new `MutationFinder` using the mapping from new names to original names given by `origins`.
+    pub(crate) fn new(origins: &'a IndexMap<Symbol, Symbol>) -> Self {
+        Self { origins, function: Symbol::intern(""), versions: IndexMap::new(), points: Vec::new() }
+    }
+
+    /// Visits a function or finalize block, whose inputs are the first versions of their variables.
+    fn visit_scope(&mut self, inputs: &[Input], block: &Block) {
+        self.versions.clear();
+        for input in inputs.iter() {
+            self.define(input.identifier().name);
+        }
+        self.visit_block(block);
+    }
+
+    /// Records `name` as the latest version of its original variable, if it renames a variable of the source.
+    fn define(&mut self, name: Symbol) {
+        if let Some(original) = self.origins.get(&name) {
+            self.versions.entry(*original).or_default().push(name);
+        }
+    }
+
+    /// Returns the variable of the source that both `left` and `right` are versions of, if any.
+    fn same_original(&self, left: &Expression, right: &Expression) -> Option<Symbol> {
+        match (left, right) {
+            (Expression::Identifier(left), Expression::Identifier(right)) => {
+                match (self.origins.get(&left.name), self.origins.get(&right.name)) {
+                    (Some(a), Some(b)) if a == b => Some(*a),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'b> ExpressionVisitor<'b> for MutationFinder<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    /// Records a mutation point for the use of `input`, if another version of its variable is in scope.
+    fn visit_identifier(&mut self, input: &'b Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        let versions = self.origins.get(&input.name).and_then(|original| self.versions.get(original));
+        if let Some(alternative) = versions.and_then(|versions| versions.iter().rev().find(|name| **name != input.name))
+        {
+            self.points.push(MutationPoint {
+                function: self.function,
+                id: input.id,
+                kind: MutationKind::SwapVersion { name: input.name, alternative: *alternative },
+            });
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'b StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for expression in input.members.iter().filter_map(|member| member.expression.as_ref()) {
+            self.visit_expression(expression, additional);
+        }
+    }
+}
+
+impl<'b> StatementVisitor<'b> for MutationFinder<'_> {
+    fn visit_assign(&mut self, input: &'b AssignStatement) {
+        self.visit_expression(&input.value, &());
+        // A phi function selects between two versions of the same variable.
+        if let Expression::Ternary(ternary) = &input.value {
+            if let Some(variable) = self.same_original(&ternary.if_true, &ternary.if_false) {
+                self.points.push(MutationPoint {
+                    function: self.function,
+                    id: ternary.id,
+                    kind: MutationKind::SwapPhiArguments { variable },
+                });
+            }
+        }
+        match &input.place {
+            Expression::Identifier(identifier) => self.define(identifier.name),
+            Expression::Tuple(tuple) => {
+                for element in tuple.elements.iter() {
+                    if let Expression::Identifier(identifier) = element {
+                        self.define(identifier.name);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Visits each branch with the versions in scope before the conditional, since the versions of one branch are not in scope in the other.
+    fn visit_conditional(&mut self, input: &'b ConditionalStatement) {
+        self.visit_expression(&input.condition, &());
+        let versions = self.versions.clone();
+        self.visit_block(&input.then);
+        self.versions = versions.clone();
+        if let Some(otherwise) = input.otherwise.as_ref() {
+            self.visit_statement(otherwise);
+        }
+        self.versions = versions;
+    }
+}
+
+impl<'b> ProgramVisitor<'b> for MutationFinder<'_> {
+    fn visit_function(&mut self, input: &'b Function) {
+        self.function = input.identifier.name;
+        self.visit_scope(&input.input, &input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_scope(&finalize.input, &finalize.block);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    AssignStatement,
+    DefinitionStatement,
+    Expression,
+    ExpressionVisitor,
+    Identifier,
+    IterationStatement,
+    StatementVisitor,
+    StructExpression,
+};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// Collects the names of the variables in a block, e.g. to check that the names introduced by the pass do not collide with them.
+#[derive(Default)]
+pub(crate) struct NameCollector {
+    /// The names of the variables, mapped to the span of their first occurrence.
+    pub(crate) names: IndexMap<Symbol, Span>,
+    /// The names of the variables that are defined or assigned in the block.
+    pub(crate) defined: IndexSet<Symbol>,
+}
+
+impl NameCollector {
+    /// Records `identifier`, keeping the span of its first occurrence.
+    fn collect(&mut self, identifier: &Identifier) {
+        self.names.entry(identifier.name).or_insert(identifier.span);
+    }
+
+    /// Records each name defined or assigned by `place`.
+    fn define(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(identifier) => {
+                self.defined.insert(identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.define(element)),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for NameCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.collect(input);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A member without an expression uses the name of the member.
+                None => self.collect(&member.identifier),
+            }
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for NameCollector {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.define(&input.place);
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.define(&input.place);
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.collect(&input.variable);
+        self.defined.insert(input.variable.name);
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        self.visit_block(&input.block);
+    }
+}
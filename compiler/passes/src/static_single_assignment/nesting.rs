@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    Block,
+    ConditionalStatement,
+    Expression,
+    ExpressionVisitor,
+    IterationStatement,
+    StatementVisitor,
+    StructExpression,
+};
+
+/// Measures the deepest nesting of expressions in a statement, e.g. `a + (b * c)` has depth two.
+/// Identifiers, literals, and unit expressions do not count towards the depth.
+/// Note that nested blocks are not visited, since each block is measured when it is consumed.
+#[derive(Default)]
+pub(crate) struct NestingDepth {
+    /// The depth of the expression currently being visited.
+    depth: usize,
+    /// The deepest nesting visited thus far.
+    pub(crate) max: usize,
+}
+
+impl<'a> ExpressionVisitor<'a> for NestingDepth {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        let nested = !matches!(
+            input,
+            Expression::Identifier(_) | Expression::Literal(_) | Expression::Unit(_) | Expression::Err(_)
+        );
+        if nested {
+            self.depth += 1;
+            self.max = self.max.max(self.depth);
+        }
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Err(_) | Expression::Identifier(_) | Expression::Literal(_) | Expression::Unit(_) => {}
+        }
+        if nested {
+            self.depth -= 1;
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for expression in input.members.iter().filter_map(|member| member.expression.as_ref()) {
+            self.visit_expression(expression, additional);
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for NestingDepth {
+    fn visit_block(&mut self, _input: &'a Block) {}
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.visit_expression(&input.condition, &());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+/// Types that are notified as the static single assignment pass consumes each function.
+/// All methods default to doing nothing, so implementors only override the notifications they need.
+pub trait SsaObserver {
+    /// Called before the block of the function named `function` is consumed.
+    fn enter_function(&mut self, _function: Symbol) {}
+
+    /// Called after the block of the function named `function` is consumed.
+    fn leave_function(&mut self, _function: Symbol) {}
+
+    /// Called before the finalize block of the function named `function` is consumed.
+    fn enter_finalize(&mut self, _function: Symbol) {}
+
+    /// Called after the finalize block of the function named `function` is consumed.
+    fn leave_finalize(&mut self, _function: Symbol) {}
+}
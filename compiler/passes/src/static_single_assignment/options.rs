@@ -0,0 +1,359 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::RecordLayout;
+
+use leo_ast::{Expression, Function, Member, ProgramId, Struct};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+use std::{fmt, sync::Arc, time::Instant};
+
+/// A transformation of names, e.g. to prefix each name introduced by the pass with a module name, or to lowercase field names.
+#[derive(Clone)]
+pub struct RenameTransform(Arc<dyn Fn(Symbol) -> Symbol + Send + Sync>);
+
+impl RenameTransform {
+    /// Returns a new `RenameTransform` that applies `transform` to each name.
+    pub fn new(transform: impl Fn(Symbol) -> Symbol + Send + Sync + 'static) -> Self {
+        Self(Arc::new(transform))
+    }
+
+    /// Applies the transformation to `symbol`.
+    pub fn apply(&self, symbol: Symbol) -> Symbol {
+        (self.0)(symbol)
+    }
+}
+
+impl fmt::Debug for RenameTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("RenameTransform")
+    }
+}
+
+/// A hook deciding the new name of each variable, given its original name and the new name proposed by the pass.
+/// The hook returns an alternative name, e.g. to keep the original name to match an external interface, or `None` to accept the proposal.
+#[derive(Clone)]
+pub struct RenameHook(Arc<dyn Fn(Symbol, Symbol) -> Option<Symbol> + Send + Sync>);
+
+impl RenameHook {
+    /// Returns a new `RenameHook` that uses `decide` to decide each new name.
+    pub fn new(decide: impl Fn(Symbol, Symbol) -> Option<Symbol> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(decide))
+    }
+
+    /// Returns the name chosen for `original`, given the `proposed` name.
+    pub fn decide(&self, original: Symbol, proposed: Symbol) -> Symbol {
+        (self.0)(original, proposed).unwrap_or(proposed)
+    }
+}
+
+impl fmt::Debug for RenameHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("RenameHook")
+    }
+}
+
+/// A check of each new name of a variable before it is committed, given the proposed name and the variables in scope with their current names.
+/// The check returns `false` to reject the name, in which case the pass retries with another name. The default check rejects a name already in scope.
+#[derive(Clone)]
+pub struct CollisionChecker(Arc<CollisionCheck>);
+
+/// The check of a `CollisionChecker`.
+type CollisionCheck = dyn Fn(Symbol, &IndexMap<Symbol, Symbol>) -> bool + Send + Sync;
+
+impl CollisionChecker {
+    /// The number of names the pass proposes for a variable before it reports that all of them were rejected.
+    pub const MAX_ATTEMPTS: usize = 8;
+
+    /// Returns a new `CollisionChecker` that uses `accepts` to check each new name.
+    pub fn new(accepts: impl Fn(Symbol, &IndexMap<Symbol, Symbol>) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(accepts))
+    }
+
+    /// Returns `true` if `proposed` may be committed, given the variables in `scope` with their current names.
+    pub fn accepts(&self, proposed: Symbol, scope: &IndexMap<Symbol, Symbol>) -> bool {
+        (self.0)(proposed, scope)
+    }
+}
+
+impl Default for CollisionChecker {
+    fn default() -> Self {
+        Self::new(|proposed, scope| !scope.values().any(|name| *name == proposed))
+    }
+}
+
+impl fmt::Debug for CollisionChecker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CollisionChecker")
+    }
+}
+
+/// A resolver of the strings of symbols in diagnostics, e.g. for an embedding whose symbols come from a custom interner.
+#[derive(Clone)]
+pub struct SymbolResolver(Arc<dyn Fn(Symbol) -> String + Send + Sync>);
+
+impl SymbolResolver {
+    /// Returns a new `SymbolResolver` that uses `resolve` to render each symbol.
+    pub fn new(resolve: impl Fn(Symbol) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(resolve))
+    }
+
+    /// Returns the string of `symbol`.
+    pub fn resolve(&self, symbol: Symbol) -> String {
+        (self.0)(symbol)
+    }
+}
+
+impl fmt::Debug for SymbolResolver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SymbolResolver")
+    }
+}
+
+/// A rewriting of struct definitions, run before the members of records are ordered, e.g. to add a computed field.
+/// Note that the rewriting is applied again to the definition of each initializer and member access, so it must be deterministic.
+#[derive(Clone)]
+pub struct StructRewriter {
+    /// The rewriting of each struct.
+    rewrite: Arc<dyn Fn(Struct) -> Struct + Send + Sync>,
+    /// The initializer of each member added by the rewriting, if any.
+    default: Option<Arc<MemberDefault>>,
+}
+
+/// The initializer of a member added by a `StructRewriter`.
+type MemberDefault = dyn Fn(&Member) -> Option<Expression> + Send + Sync;
+
+impl StructRewriter {
+    /// Returns a new `StructRewriter` that uses `rewrite` to rewrite each struct.
+    pub fn new(rewrite: impl Fn(Struct) -> Struct + Send + Sync + 'static) -> Self {
+        Self { rewrite: Arc::new(rewrite), default: None }
+    }
+
+    /// Sets `default` to give the initializer of each member added by the rewriting, which the source cannot initialize.
+    /// The initializer is given a new ID and the type of its member, so it should be a single expression, e.g. a literal.
+    pub fn with_default(self, default: impl Fn(&Member) -> Option<Expression> + Send + Sync + 'static) -> Self {
+        Self { default: Some(Arc::new(default)), ..self }
+    }
+
+    /// Returns the rewritten `struct_`.
+    pub fn rewrite(&self, struct_: Struct) -> Struct {
+        (self.rewrite)(struct_)
+    }
+
+    /// Returns the initializer of `member`, added by the rewriting, if a default is set and gives one.
+    pub fn default_initializer(&self, member: &Member) -> Option<Expression> {
+        self.default.as_ref().and_then(|default| default(member))
+    }
+}
+
+impl fmt::Debug for StructRewriter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("StructRewriter")
+    }
+}
+
+/// A resolver of the record layout targeted by each program scope, e.g. in a codebase mixing record encodings.
+#[derive(Clone)]
+pub struct LayoutResolver(Arc<dyn Fn(&ProgramId) -> RecordLayout + Send + Sync>);
+
+impl LayoutResolver {
+    /// Returns a new `LayoutResolver` that uses `resolve` to look up the layout of each program scope.
+    pub fn new(resolve: impl Fn(&ProgramId) -> RecordLayout + Send + Sync + 'static) -> Self {
+        Self(Arc::new(resolve))
+    }
+
+    /// Returns the layout of the program scope named `program_id`.
+    pub fn resolve(&self, program_id: &ProgramId) -> RecordLayout {
+        (self.0)(program_id)
+    }
+}
+
+impl fmt::Debug for LayoutResolver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LayoutResolver")
+    }
+}
+
+/// The compilation targets of a program, used to skip the functions gated to inactive targets.
+/// A function is gated by each of its annotations that names a known target, e.g. `@testnet`, and is active if any of them is active.
+/// Functions without a target annotation are always active.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TargetFilter {
+    /// The names of the known targets.
+    targets: IndexSet<Symbol>,
+    /// The names of the targets being compiled for.
+    active: IndexSet<Symbol>,
+}
+
+impl TargetFilter {
+    /// Returns a new `TargetFilter` over the known `targets`, of which those in `active` are being compiled for.
+    pub fn new(targets: impl IntoIterator<Item = Symbol>, active: impl IntoIterator<Item = Symbol>) -> Self {
+        Self { targets: targets.into_iter().collect(), active: active.into_iter().collect() }
+    }
+
+    /// Returns `true` if `function` is compiled for one of the active targets.
+    pub fn is_active(&self, function: &Function) -> bool {
+        let gates: Vec<Symbol> = function
+            .annotations
+            .iter()
+            .map(|annotation| annotation.identifier.name)
+            .filter(|name| self.targets.contains(name))
+            .collect();
+        gates.is_empty() || gates.iter().any(|name| self.active.contains(name))
+    }
+}
+
+/// The scheme used to number the names introduced by the pass.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NumberingScheme {
+    /// Each name is suffixed with a counter shared by the whole program, e.g. `a$7`.
+    #[default]
+    PerFunction,
+    /// Each name is suffixed with the path of its block and an index within the block, e.g. `a$0_1$2`.
+    /// The path lists the position of each enclosing block among its siblings, starting from the function block.
+    PerBlock,
+    /// Each variable is named after its position rather than its source name, e.g. `$v$7`, with the counter of `PerFunction`.
+    /// Programs that only differ in the names of their variables produce identical output, e.g. to check them for equivalence.
+    /// Note that the inputs keep their names, since they are part of the interface of a function.
+    Canonical,
+}
+
+/// The policy for the structs of a program scope that are not referenced by any function, mapping, or other struct.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnreferencedStructPolicy {
+    /// The unreferenced structs are kept in the output.
+    #[default]
+    Keep,
+    /// The unreferenced structs are removed from the output.
+    Prune,
+}
+
+/// Options configuring the static single assignment pass.
+/// Cloning the options is cheap, since the closures they hold are reference counted. The closures must be `Send` and `Sync`,
+/// so that the options can be shared between threads.
+#[derive(Clone, Debug, Default)]
+pub struct SsaOptions {
+    /// Whether to warn when a definition in a function body shadows one of the function's inputs.
+    pub warn_shadowed_inputs: bool,
+    /// Whether to pin the names of the inputs of each function, reporting each definition in the body that reuses the name of an input.
+    /// By default, such a definition shadows the input and is renamed like any other variable.
+    pub pin_input_names: bool,
+    /// Whether to report the reassignment of constant inputs.
+    /// Note that type checking already rejects such reassignments, so this is only useful when type checking is skipped.
+    /// Local `const` declarations are not checked, since loop unrolling propagates and removes them before this pass.
+    pub check_constant_input_reassignment: bool,
+    /// The layout used to order the members of records.
+    pub record_layout: RecordLayout,
+    /// The resolver of the layout of each program scope, or `None` to use `record_layout` for all scopes.
+    pub layout_resolver: Option<LayoutResolver>,
+    /// A transformation applied to the names of the fields of records that are not reserved, or `None` if the names are kept.
+    /// For example, the names may be lowercased for a case-insensitive target. The members and initializers are renamed accordingly.
+    pub field_name_transform: Option<RenameTransform>,
+    /// A rewriting applied to each struct before its members are ordered, or `None` if structs are kept as is.
+    /// The initializers of each struct are ordered by its rewritten definition, dropping the members removed by the rewriting.
+    /// Note that the source cannot initialize a member added by the rewriting, so it is initialized by `StructRewriter::with_default`,
+    /// and each initializer of a struct with an added member without a default is reported.
+    pub struct_rewriter: Option<StructRewriter>,
+    /// Whether to report each record whose fields are not in the order given by the layout, rather than only reordering them.
+    /// This is useful to reject non-canonical source, e.g. in continuous integration.
+    pub strict_records: bool,
+    /// Whether to warn about each record whose fields needed reordering, since a prior pass is expected to have normalized the records.
+    /// Unlike `strict_records`, the records are still reordered, so this only validates the assumed order of the passes in a pipeline.
+    pub expect_normalized_records: bool,
+    /// Whether to stop at the first record whose fields are not in the order given by the layout, reporting it as `strict_records` does.
+    /// The record and the remainder of the program are left unchanged, and the record can be queried with `StaticSingleAssigner::first_reordering`.
+    pub abort_on_reordering: bool,
+    /// Whether to keep the members of records in source order, rather than arranging them by the layout.
+    /// The order given by the layout is still computed, and can be queried with `StaticSingleAssigner::canonical_order`.
+    pub preserve_member_order: bool,
+    /// Whether to warn when the `owner` or `gates` field of a record is not declared in the position given by the layout.
+    /// Each field has its own warning, so that the misplaced field is identified, even though the pass reorders the fields.
+    pub warn_misplaced_reserved_fields: bool,
+    /// Whether to note each record omitting an optional reserved field of the layout, e.g. `gates` with `RecordLayout::migration`.
+    pub note_absent_optional_fields: bool,
+    /// Whether to warn when a field of a record resembles a reserved field of the layout, e.g. `owner_id`.
+    pub warn_near_reserved_fields: bool,
+    /// The policy for unreferenced structs. Either way, they can be queried with `StaticSingleAssigner::unreferenced_structs`.
+    pub unreferenced_structs: UnreferencedStructPolicy,
+    /// The maximum number of temporaries the pass may introduce in each function, including its finalize block, or `None` if there is no limit.
+    pub max_temporaries: Option<usize>,
+    /// The maximum number of functions in a program and its imports, or `None` if there is no limit.
+    /// A program exceeding the limit is reported and returned unchanged, before any of it is consumed.
+    pub max_functions: Option<usize>,
+    /// Whether to consume the structs and functions of a program scope in source order, rather than all structs first.
+    /// This ensures that diagnostics are emitted in the order in which they appear in the source.
+    pub source_order: bool,
+    /// A transformation applied to each new name before it is stored, or `None` if new names are used as is.
+    /// Note that the transformation must be injective, so that the new names remain unique.
+    pub rename_transform: Option<RenameTransform>,
+    /// A hook deciding the new name of each variable, or `None` to use the names proposed by the pass.
+    /// Note that the temporaries introduced by the pass are not passed to the hook.
+    pub rename_hook: Option<RenameHook>,
+    /// The check of each new name of a variable before it is committed, or `None` to commit the names as decided.
+    /// Note that the names proposed by the pass are unique by construction, so a check is only needed for custom naming schemes.
+    pub collision_checker: Option<CollisionChecker>,
+    /// The resolver used to render the symbols in diagnostics, or `None` to use the global interner.
+    pub symbol_resolver: Option<SymbolResolver>,
+    /// The scheme used to number the new names.
+    pub numbering: NumberingScheme,
+    /// Whether to prefix each name introduced in a program scope with the name of the scope, e.g. `token$a$3` rather than `a$3`.
+    /// This keeps the names of different scopes disjoint, e.g. when the scopes are later flattened into one program.
+    pub prefix_scope_names: bool,
+    /// Whether to introduce names that are valid Leo identifiers, e.g. `a__3` rather than `a$3`, so that the output can be parsed again.
+    /// Since such names may collide with the names in the source, each collision is reported.
+    pub valid_identifiers: bool,
+    /// Whether to record the renamings introduced by each statement, e.g. for visualizing the construction of SSA form.
+    pub record_timeline: bool,
+    /// Whether to record the variables in scope at the exit of each block, along with their new names, e.g. to seed a liveness analysis.
+    /// This is opt-in, since the variables are collected once per block.
+    pub record_block_exits: bool,
+    /// Whether to tally the kinds of the statements and expressions consumed, e.g. to check that test fixtures exercise every kind.
+    pub record_coverage: bool,
+    /// Whether to retain the final `RenameTable` of each function and finalize block after it is consumed.
+    pub retain_rename_tables: bool,
+    /// The number of entries each `RenameTable` scope stores inline before switching to a map, or `None` for the default.
+    pub rename_table_threshold: Option<usize>,
+    /// The maximum number of entries in the rename table across the scopes on the stack, or `None` if there is no limit.
+    /// The first function exceeding the limit is reported, and the functions remaining once it is consumed are left unchanged.
+    pub max_rename_entries: Option<usize>,
+    /// The name of the only function to consume, or `None` to consume all functions.
+    /// The other functions are left in source form, which is useful for reviewing the SSA form of a single function.
+    pub focus: Option<Symbol>,
+    /// The targets used to skip the functions gated to inactive targets, or `None` to consume all functions.
+    /// The skipped functions are passed through unchanged, since they are not compiled for the current target.
+    pub target_filter: Option<TargetFilter>,
+    /// The ratio of output to input statements above which a function is reported, or `None` to not report any function.
+    pub expansion_warning_threshold: Option<f64>,
+    /// The number of temporaries above which a function is reported, or `None` to not report any function.
+    /// Like `max_temporaries`, the threshold applies to each function separately, including its finalize block, but it only warns.
+    pub temporary_warning_threshold: Option<usize>,
+    /// The ratio of output to input statements assumed by `estimate_output_size`, or `None` for `DEFAULT_EXPANSION_FACTOR`.
+    pub expansion_factor: Option<f64>,
+    /// The instant after which the pass stops consuming functions, or `None` if there is no deadline.
+    /// The deadline is checked before each function, and the functions remaining once it passes are left unchanged.
+    pub deadline: Option<Instant>,
+}
+
+impl SsaOptions {
+    /// Returns the record layout of the program scope named `program_id`.
+    pub(crate) fn layout_of(&self, program_id: &ProgramId) -> RecordLayout {
+        match &self.layout_resolver {
+            Some(resolver) => resolver.resolve(program_id),
+            None => self.record_layout.clone(),
+        }
+    }
+}
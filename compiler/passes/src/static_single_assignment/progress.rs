@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SsaObserver;
+
+use leo_span::Symbol;
+
+use std::sync::mpsc::Sender;
+
+/// A progress event sent as the static single assignment pass consumes each function, e.g. to drive a progress bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// The pass started consuming the block of the function named `function`, the `index`-th of `total` functions counting from zero.
+    FunctionStarted { function: Symbol, index: usize, total: usize },
+    /// The pass finished consuming the block of the function named `function`, the `finished`-th of `total` functions.
+    FunctionFinished { function: Symbol, finished: usize, total: usize },
+    /// The pass started consuming the finalize block of the function named `function`.
+    FinalizeStarted { function: Symbol },
+    /// The pass finished consuming the finalize block of the function named `function`.
+    FinalizeFinished { function: Symbol },
+}
+
+/// An `SsaObserver` that sends a `ProgressEvent` over a channel for each function and finalize block.
+pub(crate) struct ProgressReporter {
+    /// The sender of the events. Note that events are dropped once the receiver disconnects.
+    sender: Sender<ProgressEvent>,
+    /// The number of functions in the program that the options of the pass select.
+    total: usize,
+    /// The number of functions started thus far.
+    started: usize,
+}
+
+impl ProgressReporter {
+    /// Returns a new `ProgressReporter` for a program with `total` functions.
+    pub(crate) fn new(sender: Sender<ProgressEvent>, total: usize) -> Self {
+        Self { sender, total, started: 0 }
+    }
+
+    /// Sends `event`, ignoring a disconnected receiver, since progress is only informative.
+    fn send(&self, event: ProgressEvent) {
+        let _ = self.sender.send(event);
+    }
+}
+
+impl SsaObserver for ProgressReporter {
+    fn enter_function(&mut self, function: Symbol) {
+        self.send(ProgressEvent::FunctionStarted { function, index: self.started, total: self.total });
+        self.started += 1;
+    }
+
+    fn leave_function(&mut self, function: Symbol) {
+        self.send(ProgressEvent::FunctionFinished { function, finished: self.started, total: self.total });
+    }
+
+    fn enter_finalize(&mut self, function: Symbol) {
+        self.send(ProgressEvent::FinalizeStarted { function });
+    }
+
+    fn leave_finalize(&mut self, function: Symbol) {
+        self.send(ProgressEvent::FinalizeFinished { function });
+    }
+}
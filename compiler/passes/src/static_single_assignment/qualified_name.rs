@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Function, Program, ProgramId};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt;
+
+/// The name of a function qualified by the program that declares it, e.g. `token.aleo::transfer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QualifiedName {
+    /// The program declaring the function.
+    pub program: ProgramName,
+    /// The name of the function.
+    pub function: Symbol,
+}
+
+impl fmt::Display for QualifiedName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.program, self.function)
+    }
+}
+
+/// The name of a program, e.g. `token.aleo`.
+/// Unlike a `ProgramId`, the name has no spans, so the names of a program parsed from different sources are equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProgramName {
+    /// The name of the program, e.g. `token`.
+    pub name: Symbol,
+    /// The network of the program, e.g. `aleo`.
+    pub network: Symbol,
+}
+
+impl From<ProgramId> for ProgramName {
+    fn from(program_id: ProgramId) -> Self {
+        Self { name: program_id.name.name, network: program_id.network.name }
+    }
+}
+
+impl fmt::Display for ProgramName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.name, self.network)
+    }
+}
+
+/// The name of a record qualified by the program that declares it, e.g. `token.aleo/Token`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QualifiedRecord {
+    /// The program declaring the record.
+    pub program: ProgramName,
+    /// The name of the record.
+    pub record: Symbol,
+}
+
+impl fmt::Display for QualifiedRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.program, self.record)
+    }
+}
+
+/// Returns each function of `program` and its imports with its qualified name, e.g. to build a global index of the functions.
+/// The functions of a program precede those of its imports, in order, and a program imported more than once is listed once.
+pub fn qualified_functions(program: &Program) -> Vec<(QualifiedName, Function)> {
+    let mut functions = IndexMap::new();
+    visit_qualified_functions(program, |name, function| {
+        functions.entry(name).or_insert_with(|| function.clone());
+    });
+    functions.into_iter().collect()
+}
+
+/// Returns the number of functions of `program` and its imports, counted as in `qualified_functions` but without cloning them.
+pub fn count_qualified_functions(program: &Program) -> usize {
+    let mut names = IndexSet::new();
+    visit_qualified_functions(program, |name, _| {
+        names.insert(name);
+    });
+    names.len()
+}
+
+/// Calls `visit` with each function of `program` and its imports and its qualified name, in the order of `qualified_functions`.
+/// Note that the functions of a program imported more than once are visited once per import.
+pub(crate) fn visit_qualified_functions<'a>(program: &'a Program, mut visit: impl FnMut(QualifiedName, &'a Function)) {
+    let mut programs = vec![program];
+    while let Some(program) = programs.pop() {
+        for scope in program.program_scopes.values() {
+            for (name, function) in scope.functions.iter() {
+                visit(QualifiedName { program: scope.program_id.into(), function: *name }, function);
+            }
+        }
+        // Push the imports in reverse, so that they are listed in order.
+        programs.extend(program.imports.values().rev().map(|(import, _)| import));
+    }
+}
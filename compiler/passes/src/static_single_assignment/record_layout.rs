@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::QualifiedRecord;
+
+use leo_ast::Member;
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexMap;
+use std::fmt;
+
+/// The layout of the members of a record.
+/// The leading reserved members are hoisted to the beginning of the record, and the trailing reserved members are moved to its end,
+/// in the order given by the layout. All other members are placed in between, in the order given by `FieldOrder`.
+///
+/// Note that the `gates` member is no longer part of the record encoding, so the default layout only hoists `owner`.
+/// Layouts for other encodings, e.g. `[gates, owner]`, can be specified with `RecordLayout::new`.
+/// During the migration away from `gates`, `RecordLayout::migration` accepts records both with and without it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordLayout {
+    /// The names of the reserved members, in the order in which they appear at the beginning of the record.
+    leading: Vec<Symbol>,
+    /// The names of the reserved members, in the order in which they appear at the end of the record.
+    trailing: Vec<Symbol>,
+    /// The order of the members that are not reserved.
+    remaining: FieldOrder,
+    /// The names of the reserved members that a record may omit.
+    optional: Vec<Symbol>,
+}
+
+/// The order of the members of a record that are not reserved.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FieldOrder {
+    /// The members are kept in the order in which they are declared.
+    #[default]
+    Source,
+    /// The members are sorted alphabetically by name, e.g. to compare records structurally.
+    Alphabetical,
+}
+
+impl Default for RecordLayout {
+    fn default() -> Self {
+        Self::new(vec![sym::owner])
+    }
+}
+
+impl RecordLayout {
+    /// Returns a new `RecordLayout` that hoists the members named in `leading`, in order.
+    /// The remaining members are kept in source order.
+    pub fn new(leading: Vec<Symbol>) -> Self {
+        Self { leading, trailing: Vec::new(), remaining: FieldOrder::Source, optional: Vec::new() }
+    }
+
+    /// Returns the layout for the migration away from `gates`, which hoists `owner` and then `gates`, but allows records to omit `gates`.
+    pub fn migration() -> Self {
+        Self::new(vec![sym::owner, sym::gates]).with_optional(vec![sym::gates])
+    }
+
+    /// Returns the layout with the reserved members named in `optional` allowed to be omitted from a record.
+    pub fn with_optional(self, optional: Vec<Symbol>) -> Self {
+        Self { optional, ..self }
+    }
+
+    /// Returns `true` if `name` is a reserved member that a record may omit in this layout.
+    pub fn is_optional(&self, name: Symbol) -> bool {
+        self.optional.contains(&name)
+    }
+
+    /// Returns the layout with the members named in `trailing` moved to the end of the record, in order.
+    pub fn with_trailing(self, trailing: Vec<Symbol>) -> Self {
+        Self { trailing, ..self }
+    }
+
+    /// Returns the layout with the remaining members ordered by `order`.
+    pub fn with_remaining_order(self, order: FieldOrder) -> Self {
+        Self { remaining: order, ..self }
+    }
+
+    /// Returns the order of the members that are not reserved.
+    pub fn remaining_order(&self) -> FieldOrder {
+        self.remaining
+    }
+
+    /// Returns the names of the reserved members that are hoisted to the beginning of the record.
+    pub fn leading(&self) -> &[Symbol] {
+        &self.leading
+    }
+
+    /// Returns the names of the reserved members that are moved to the end of the record.
+    pub fn trailing(&self) -> &[Symbol] {
+        &self.trailing
+    }
+
+    /// Returns the names of all reserved members, leading members first.
+    pub fn reserved(&self) -> impl Iterator<Item = &Symbol> {
+        self.leading.iter().chain(self.trailing.iter())
+    }
+
+    /// Returns `true` if `name` is a reserved member in this layout.
+    pub fn is_reserved(&self, name: Symbol) -> bool {
+        self.leading.contains(&name) || self.trailing.contains(&name)
+    }
+
+    /// Returns the reserved member that `name` resembles without matching it, if any.
+    /// A name resembles a reserved member if it contains the reserved name, e.g. `owner_id`, or differs from it in a single character, e.g. `owners`.
+    pub fn resembled_reserved(&self, name: Symbol) -> Option<Symbol> {
+        if self.is_reserved(name) {
+            return None;
+        }
+        let name = name.to_string().to_lowercase();
+        self.reserved().copied().find(|reserved| {
+            let reserved = reserved.to_string();
+            name.contains(&reserved) || edit_distance(&name, &reserved) <= 1
+        })
+    }
+
+    /// Arranges `items` according to this layout, where `name` returns the name of an item.
+    /// Returns the arranged items along with the names of the reserved members that are missing from `items`, including the optional ones.
+    /// Note that if several items have the same name, only the last is kept, in the position of the first.
+    pub fn arrange<T>(&self, items: Vec<T>, name: impl Fn(&T) -> Symbol) -> (Vec<T>, Vec<Symbol>) {
+        let mut arranged = Vec::with_capacity(items.len());
+        let mut missing = Vec::new();
+        let mut item_map: IndexMap<Symbol, T> = items.into_iter().map(|item| (name(&item), item)).collect();
+
+        // Remove the reserved members, in the order given by the layout.
+        let mut take = |names: &[Symbol]| {
+            names
+                .iter()
+                .filter_map(|name| {
+                    let item = item_map.shift_remove(name);
+                    if item.is_none() {
+                        missing.push(*name);
+                    }
+                    item
+                })
+                .collect::<Vec<_>>()
+        };
+        let leading = take(&self.leading);
+        let trailing = take(&self.trailing);
+
+        // Place the remaining members between the leading and trailing members.
+        let mut remaining: Vec<(Symbol, T)> = item_map.into_iter().collect();
+        if self.remaining == FieldOrder::Alphabetical {
+            remaining.sort_by_cached_key(|(name, _)| name.to_string());
+        }
+        arranged.extend(leading);
+        arranged.extend(remaining.into_iter().map(|(_, item)| item));
+        arranged.extend(trailing);
+
+        (arranged, missing)
+    }
+
+    /// Returns `true` if `members` are already in the order prescribed by this layout.
+    /// Note that the optional reserved members may be omitted.
+    pub fn is_canonical(&self, members: &[Member]) -> bool {
+        let names: Vec<Symbol> = members.iter().map(Member::name).collect();
+        let (arranged, missing) = self.arrange(names.clone(), |name| *name);
+        missing.iter().all(|name| self.is_optional(*name)) && arranged == names
+    }
+}
+
+/// A reserved field moved by the pass to the position given by the record layout, e.g. to report the migration of a record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldHoist {
+    /// The record declaring the field.
+    pub record: QualifiedRecord,
+    /// The name of the field.
+    pub field: Symbol,
+    /// The index of the field in the source.
+    pub from: usize,
+    /// The index of the field in the output.
+    pub to: usize,
+}
+
+impl fmt::Display for FieldHoist {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: `{}` moved from position {} to {}", self.record, self.field, self.from + 1, self.to + 1)
+    }
+}
+
+/// A record whose fields are not in the order given by its layout, along with that order, e.g. for a quick fix that reorders the record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordReordering {
+    /// The record needing the reordering.
+    pub record: QualifiedRecord,
+    /// The names of the fields in the order given by the layout.
+    pub order: Vec<Symbol>,
+}
+
+/// The positions of the `owner` and `gates` fields of a record in the source, before it is reordered, e.g. to audit records before a migration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReservedFieldPositions {
+    /// The record declaring the fields.
+    pub record: QualifiedRecord,
+    /// The index of the `owner` field in the source, or `None` if the record does not declare it.
+    pub owner: Option<usize>,
+    /// The index of the `gates` field in the source, or `None` if the record does not declare it.
+    pub gates: Option<usize>,
+}
+
+impl fmt::Display for ReservedFieldPositions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let position =
+            |index: Option<usize>| index.map_or("absent".to_string(), |index| format!("at position {}", index + 1));
+        write!(f, "{}: `owner` {}, `gates` {}", self.record, position(self.owner), position(self.gates))
+    }
+}
+
+/// Returns the number of single character insertions, deletions, or substitutions needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::StaticSingleAssigner;
+use crate::{ScopeStack, StaticSingleAssigner};
 
 use leo_ast::{
     AccessExpression,
@@ -29,21 +29,23 @@ use leo_ast::{
     Identifier,
     Literal,
     MemberAccess,
+    Node,
     Statement,
-    Struct,
     StructExpression,
     StructVariableInitializer,
     TernaryExpression,
     TupleAccess,
     TupleExpression,
+    Type,
     UnaryExpression,
     UnitExpression,
 };
+use leo_errors::StaticSingleAssignerError;
 use leo_span::{sym, Symbol};
 
 use indexmap::IndexMap;
 
-impl ExpressionConsumer for StaticSingleAssigner<'_> {
+impl<S: ScopeStack> ExpressionConsumer for StaticSingleAssigner<'_, S> {
     type Output = (Expression, Vec<Statement>);
 
     /// Consumes an access expression, accumulating any statements that are generated.
@@ -79,11 +81,20 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
                     }
                 }
 
+                // The field of a record is renamed along with the members of the record.
+                let name = match self.type_table.get(&member.inner.id()) {
+                    Some(Type::Identifier(struct_name)) => Identifier {
+                        name: self.transformed_field_name(struct_name.name, member.name.name),
+                        ..member.name
+                    },
+                    _ => member.name,
+                };
+
                 let (expr, statements) = self.consume_expression(*member.inner);
                 (
                     AccessExpression::Member(MemberAccess {
                         inner: Box::new(expr),
-                        name: member.name,
+                        name,
                         span: member.span,
                         id: member.id,
                     }),
@@ -250,32 +261,56 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
 
         // Reorder the members to match that of the struct definition.
 
-        // Lookup the struct definition.
+        // Lookup the struct definition, rewritten as in `consume_struct`.
         // Note that type checking guarantees that the correct struct definition exists.
-        let struct_definition: &Struct = self.symbol_table.lookup_struct(input.name.name).unwrap();
-
-        // Initialize the list of reordered members.
-        let mut reordered_members = Vec::with_capacity(members.len());
+        let struct_definition = self.struct_definition(input.name.name).unwrap();
 
         // Collect the members of the init expression into a map.
         let mut member_map: IndexMap<Symbol, StructVariableInitializer> =
             members.into_iter().map(|member| (member.identifier.name, member)).collect();
 
-        // If we are initializing a record, add the `owner` first.
-        // Note that type checking guarantees that the above fields exist.
-        if struct_definition.is_record {
-            // Add the `owner` field.
-            // Note that the `unwrap` is safe, since type checking guarantees that the member exists.
-            reordered_members.push(member_map.remove(&sym::owner).unwrap());
-        }
+        // The members are ordered as in the output of `consume_struct`.
+        // Note that a missing reserved field has already been reported by `consume_struct`.
+        let names = self.output_member_names(&struct_definition);
 
         // For each member of the struct definition, push the corresponding member of the init expression.
-        for member in &struct_definition.members {
-            // If the member is part of a record and it is `owner` then we have already added it.
-            if !(struct_definition.is_record && matches!(member.identifier.name, sym::owner)) {
-                // Lookup and push the member of the init expression.
-                // Note that the `unwrap` is safe, since type checking guarantees that the member exists.
-                reordered_members.push(member_map.remove(&member.identifier.name).unwrap());
+        // Note that type checking guarantees that each member declared in the source is initialized, so only a member added by
+        // the struct rewriter may be missing, in which case it is initialized by the default of the rewriter, if it gives one.
+        // The initializers of the members removed by the struct rewriter are dropped.
+        // The initializers of the fields of a record are renamed along with the members of the record.
+        let mut reordered_members: Vec<StructVariableInitializer> = Vec::with_capacity(names.len());
+        for (name, output_name) in names {
+            if let Some(member) = member_map.remove(&name) {
+                reordered_members.push(StructVariableInitializer {
+                    identifier: Identifier { name: output_name, ..member.identifier },
+                    ..member
+                });
+                continue;
+            }
+            let added = struct_definition.members.iter().find(|member| member.name() == name);
+            let default = added.and_then(|member| {
+                let rewriter = self.options.struct_rewriter.as_ref()?;
+                Some((rewriter.default_initializer(member)?, member.type_.clone()))
+            });
+            match default {
+                Some((mut expression, type_)) => {
+                    // The default is a new expression, with the type of the member it initializes.
+                    expression.set_id(self.node_builder.next_id());
+                    self.type_table.insert(expression.id(), type_);
+                    let (expression, mut stmts) = self.consume_expression(expression);
+                    statements.append(&mut stmts);
+                    reordered_members.push(StructVariableInitializer {
+                        identifier: Identifier::new(output_name, self.node_builder.next_id()),
+                        expression: Some(expression),
+                        span: input.span,
+                        id: self.node_builder.next_id(),
+                    });
+                }
+                None => self.emit_err(StaticSingleAssignerError::rewritten_member_uninitialized(
+                    self.resolve(input.name.name),
+                    self.resolve(name),
+                    input.span,
+                )),
             }
         }
 
@@ -296,15 +331,40 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         let name = match self.is_lhs {
             // If consuming the left-hand side of a definition or assignment, a new unique name is introduced.
             true => {
-                let new_name = self.assigner.unique_symbol(identifier.name, "$");
-                self.rename_table.update(identifier.name, new_name, identifier.id);
+                let new_name = self.new_name(identifier.name, identifier.span);
+                self.rename(identifier.name, new_name, identifier.id);
                 new_name
             }
             // Otherwise, we look up the previous name in the `RenameTable`.
             // Note that we do not panic if the identifier is not found in the rename table.
             // Variables that do not exist in the rename table are ones that have been introduced during the SSA pass.
             // These variables are never re-assigned, and will never have an entry in the rename-table.
-            false => *self.rename_table.lookup(identifier.name).unwrap_or(&identifier.name),
+            false => match self.rename_table.lookup(identifier.name) {
+                Some(name) => {
+                    // A name in the source that has not been defined in the current block is free.
+                    // Note that the names introduced by the pass are not in the source, so they are never free.
+                    if self.locals.variables.contains(&identifier.name)
+                        && !self.locals.bound_names.contains(&identifier.name)
+                    {
+                        self.locals.free_names.insert(identifier.name);
+                    }
+                    *name
+                }
+                // A variable that is defined later in the function is used before its definition.
+                // Note that prior passes reject such uses, so this only occurs in malformed input.
+                // Similarly, `self` is only valid in `self.caller` and `self.signer`, since there are no methods with a receiver to seed.
+                None => {
+                    if identifier.name == sym::SelfLower {
+                        self.emit_err(StaticSingleAssignerError::unsupported_receiver(identifier.span));
+                    } else if self.locals.defined_names.contains(&identifier.name) {
+                        self.emit_err(StaticSingleAssignerError::use_before_definition(
+                            self.resolve(identifier.name),
+                            identifier.span,
+                        ));
+                    }
+                    identifier.name
+                }
+            },
         };
 
         (Expression::Identifier(Identifier { name, span: identifier.span, id: identifier.id }), Default::default())
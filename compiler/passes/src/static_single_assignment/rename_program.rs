@@ -14,44 +14,267 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::StaticSingleAssigner;
+use crate::{
+    count_qualified_functions,
+    count_statements,
+    FieldHoist,
+    FunctionSignature,
+    QualifiedName,
+    QualifiedRecord,
+    RecordReordering,
+    ReservedFieldPositions,
+    ScopeStack,
+    StatementExpansion,
+    StaticSingleAssigner,
+    StructReferences,
+    UnreferencedStructPolicy,
+};
 
 use leo_ast::{
     Block,
-    Finalize,
     Function,
     FunctionConsumer,
+    Identifier,
     Member,
     Program,
     ProgramConsumer,
     ProgramScope,
     ProgramScopeConsumer,
+    ProgramVisitor,
     StatementConsumer,
     Struct,
     StructConsumer,
 };
-use leo_span::{sym, Symbol};
+use leo_errors::{StaticSingleAssignerError, StaticSingleAssignerWarning};
+use leo_span::{sym, Span, Symbol};
 
-use indexmap::IndexMap;
+use indexmap::IndexSet;
 
-impl StructConsumer for StaticSingleAssigner<'_> {
+impl<S: ScopeStack> StructConsumer for StaticSingleAssigner<'_, S> {
     type Output = Struct;
 
-    /// Reconstructs records in the program, ordering its fields such that `owner` and is the first field.
+    /// Reconstructs records in the program, ordering its fields such that the leading reserved fields of the record layout are first,
+    /// and its trailing reserved fields are last. With the default layout, `owner` is the first field.
+    /// If `options.struct_rewriter` is set, each struct is rewritten first, and the rewritten struct is ordered.
+    /// If `options.field_name_transform` is set, the fields that are not reserved are renamed before they are ordered.
+    /// If `options.strict_records` is set, each record whose fields needed reordering is also reported.
+    /// If `options.expect_normalized_records` is set, each such record is warned about instead.
+    /// If `options.abort_on_reordering` is set, the pass stops at the first such record, which is reported and left unchanged.
+    /// A record may omit the optional reserved fields of the layout, which is noted if `options.note_absent_optional_fields` is set.
+    /// If `options.preserve_member_order` is set, the fields are kept in source order, and the order given by the layout is only recorded.
+    /// Either way, the index in the output of each field is recorded against its index in the source.
+    ///
+    /// Note that structs cannot declare type parameters, so no rename scope is entered here: the members of a struct are not variables,
+    /// and the types of its members only name other structs, which are never renamed. A generic struct would need its parameters
+    /// registered in a new scope of the rename table, so that they do not shadow the variables of the enclosing function.
     fn consume_struct(&mut self, struct_: Struct) -> Self::Output {
+        // Once the pass has stopped at a record that needed reordering, the remaining structs are left unchanged.
+        if self.stop.first_reordering.is_some() {
+            return struct_;
+        }
+        let struct_ = match &self.options.struct_rewriter {
+            Some(rewriter) => rewriter.rewrite(struct_),
+            None => struct_,
+        };
         match struct_.is_record {
             false => struct_,
             true => {
-                let mut members = Vec::with_capacity(struct_.members.len());
-                let mut member_map: IndexMap<Symbol, Member> =
-                    struct_.members.into_iter().map(|member| (member.identifier.name, member)).collect();
+                // If `options.abort_on_reordering` is set, stop at this record if its fields are out of order.
+                if self.options.abort_on_reordering {
+                    let names: Vec<Symbol> = struct_.members.iter().map(Member::name).collect();
+                    let (order, _) = self.current.record_layout.arrange(names.clone(), |name| *name);
+                    if let (true, Some(program)) = (order != names, self.current.program_id) {
+                        self.emit_err(StaticSingleAssignerError::record_not_canonical(
+                            self.resolve(struct_.identifier.name),
+                            order.iter().map(|name| self.resolve(*name)).collect::<Vec<_>>().join(", "),
+                            struct_.span,
+                        ));
+                        let record = QualifiedRecord { program: program.into(), record: struct_.identifier.name };
+                        self.stop.first_reordering = Some(RecordReordering { record, order });
+                        return struct_;
+                    }
+                }
+
+                // Record the positions of the `owner` and `gates` fields before the members are reordered.
+                if let Some(program) = self.current.program_id {
+                    let position = |field| struct_.members.iter().position(|member| member.name() == field);
+                    self.records.reserved_positions.push(ReservedFieldPositions {
+                        record: QualifiedRecord { program: program.into(), record: struct_.identifier.name },
+                        owner: position(sym::owner),
+                        gates: position(sym::gates),
+                    });
+                }
+
+                // Warn about the fields that resemble, but do not match, a reserved field.
+                if self.options.warn_near_reserved_fields {
+                    for member in struct_.members.iter() {
+                        if let Some(reserved) = self.current.record_layout.resembled_reserved(member.name()) {
+                            self.emit_warning(StaticSingleAssignerWarning::field_resembles_reserved_field(
+                                self.resolve(struct_.identifier.name),
+                                self.resolve(member.name()),
+                                self.resolve(reserved),
+                                member.span,
+                            ));
+                        }
+                    }
+                }
+
+                // Warn about the `owner` and `gates` fields that are not declared in the position given by the layout.
+                // Note that the expected position of a leading field only counts the leading fields declared by the record.
+                if self.options.warn_misplaced_reserved_fields {
+                    let declared = self
+                        .current
+                        .record_layout
+                        .leading()
+                        .iter()
+                        .filter(|name| struct_.members.iter().any(|member| member.name() == **name));
+                    for (expected, name) in declared.enumerate() {
+                        let actual = struct_.members.iter().position(|member| member.name() == *name).unwrap();
+                        if actual != expected {
+                            let (record, expected, actual, span) =
+                                (self.resolve(struct_.identifier.name), expected + 1, actual + 1, struct_.span);
+                            match *name {
+                                sym::owner => self.emit_warning(StaticSingleAssignerWarning::misplaced_owner_field(
+                                    record, expected, actual, span,
+                                )),
+                                sym::gates => self.emit_warning(StaticSingleAssignerWarning::misplaced_gates_field(
+                                    record, expected, actual, span,
+                                )),
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+
+                // Check that each reserved field is declared at most once, before the fields are hoisted.
+                for name in self.current.record_layout.reserved() {
+                    for member in struct_.members.iter().filter(|member| member.identifier.name == *name).skip(1) {
+                        self.emit_err(StaticSingleAssignerError::duplicate_reserved_field(
+                            self.resolve(struct_.identifier.name),
+                            self.resolve(*name),
+                            member.span,
+                        ));
+                    }
+                }
 
-                // Add the owner field to the beginning of the members list.
-                // Note that type checking ensures that the owner field exists.
-                members.push(member_map.shift_remove(&sym::owner).unwrap());
+                // Transform the names of the fields that are not reserved, unless the transformed names collide.
+                let members = match self.transform_field_names(&struct_) {
+                    Ok(names) => struct_
+                        .members
+                        .into_iter()
+                        .map(|member| Member {
+                            identifier: Identifier { name: names[&member.name()], ..member.identifier },
+                            ..member
+                        })
+                        .collect(),
+                    Err((first, second, name)) => {
+                        self.emit_err(StaticSingleAssignerError::transformed_field_names_collide(
+                            self.resolve(struct_.identifier.name),
+                            self.resolve(first),
+                            self.resolve(second),
+                            self.resolve(name),
+                            struct_.span,
+                        ));
+                        struct_.members
+                    }
+                };
 
-                // Add the remaining fields to the members list.
-                members.extend(member_map.into_iter().map(|(_, member)| member));
+                // The types of the members, used to check that reordering only moves the members.
+                #[cfg(debug_assertions)]
+                let types: indexmap::IndexMap<_, _> =
+                    members.iter().map(|member| (member.name(), member.type_.clone())).collect();
+
+                // Arrange the fields in the order given by the layout.
+                // Note that a missing reserved field is reported, but does not stop the remaining fields from being ordered.
+                let names: Vec<Symbol> = members.iter().map(|member| member.name()).collect();
+                let (arranged_members, missing) =
+                    self.current.record_layout.arrange(members.clone(), |member| member.name());
+                let arranged: Vec<Symbol> = arranged_members.iter().map(|member| member.name()).collect();
+                self.records.canonical_orders.insert(struct_.identifier.name, arranged.clone());
+                // If `options.preserve_member_order` is set, the members are kept in source order.
+                // Note that duplicate fields are dropped as in arranging, keeping the last in the position of the first.
+                let members = match self.options.preserve_member_order {
+                    true => members
+                        .into_iter()
+                        .map(|member| (member.name(), member))
+                        .collect::<indexmap::IndexMap<_, _>>()
+                        .into_values()
+                        .collect(),
+                    false => {
+                        // Record each reserved field moved by the arrangement.
+                        if let Some(program) = self.current.program_id {
+                            let record = QualifiedRecord { program: program.into(), record: struct_.identifier.name };
+                            let hoists = self.current.record_layout.reserved().filter_map(|field| {
+                                let from = names.iter().position(|name| name == field)?;
+                                let to = arranged.iter().position(|name| name == field)?;
+                                (from != to).then_some(FieldHoist { record, field: *field, from, to })
+                            });
+                            self.records.hoists.extend(hoists.collect::<Vec<_>>());
+                        }
+                        arranged_members
+                    }
+                };
+                // Count the record if the order of its members in the output differs from their order in the source.
+                if members.iter().map(|member| member.name()).ne(names.iter().copied()) {
+                    self.summary.records_reordered += 1;
+                }
+                // Record the index in the output of the member at each index in the source.
+                // Note that a duplicate field maps to the index of the member kept in its place.
+                let indices = names
+                    .iter()
+                    .map(|name| members.iter().position(|member| member.name() == *name).unwrap())
+                    .collect();
+                self.records.member_indices.insert(struct_.identifier.name, indices);
+                // Record the types of the members in the order of the output.
+                let field_types = members.iter().map(|member| (member.name(), member.type_.clone())).collect();
+                self.records.field_types.insert(struct_.identifier.name, field_types);
+                // Note that a duplicate field, which arranging drops, has already been reported.
+                if arranged.len() == names.len() && arranged != names {
+                    let order = || arranged.iter().map(|name| self.resolve(*name)).collect::<Vec<_>>().join(", ");
+                    if self.options.strict_records {
+                        self.emit_err(StaticSingleAssignerError::record_not_canonical(
+                            self.resolve(struct_.identifier.name),
+                            order(),
+                            struct_.span,
+                        ));
+                    }
+                    if self.options.expect_normalized_records {
+                        self.emit_warning(StaticSingleAssignerWarning::record_not_normalized(
+                            self.resolve(struct_.identifier.name),
+                            order(),
+                            struct_.span,
+                        ));
+                    }
+                }
+                for name in missing {
+                    let record = self.resolve(struct_.identifier.name);
+                    // An optional reserved field may be omitted, which is only noted if requested.
+                    if self.current.record_layout.is_optional(name) {
+                        if self.options.note_absent_optional_fields {
+                            self.emit_warning(StaticSingleAssignerWarning::optional_field_absent(
+                                record,
+                                self.resolve(name),
+                                struct_.span,
+                            ));
+                        }
+                        continue;
+                    }
+                    self.emit_err(match name {
+                        sym::owner => StaticSingleAssignerError::missing_owner_field(record, struct_.span),
+                        sym::gates => StaticSingleAssignerError::missing_gates_field(record, struct_.span),
+                        _ => {
+                            StaticSingleAssignerError::missing_reserved_field(record, self.resolve(name), struct_.span)
+                        }
+                    });
+                }
+
+                #[cfg(debug_assertions)]
+                debug_assert!(
+                    members.len() == types.len()
+                        && members.iter().all(|member| types.get(&member.name()) == Some(&member.type_)),
+                    "Reordering the members of `{}` should preserve the type of each member.",
+                    struct_.identifier
+                );
 
                 Struct { members, ..struct_ }
             }
@@ -59,57 +282,69 @@ impl StructConsumer for StaticSingleAssigner<'_> {
     }
 }
 
-impl FunctionConsumer for StaticSingleAssigner<'_> {
+impl<S: ScopeStack> FunctionConsumer for StaticSingleAssigner<'_, S> {
     type Output = Function;
 
     /// Reconstructs the `Function`s in the `Program`, while allocating the appropriate `RenameTable`s.
+    /// Note that the AST has no statement defining a function, so functions cannot be nested and each function is consumed in a fresh scope.
+    /// Similarly, every function has a block, since the parser rejects functions declared without a body.
+    /// The AST does not retain comments, since the parser discards them, but the annotations, span, and ID of the function and its block are preserved,
+    /// so that a pretty-printer can recover the comments from the source spanned by the function.
+    /// The temporaries introduced in the function are counted, and the function is reported if they exceed `options.temporary_warning_threshold`
+    /// or `options.max_temporaries`.
     fn consume_function(&mut self, function: Function) -> Self::Output {
+        let name = function.identifier.name;
+        self.current.function = Some(name);
+        self.locals.reset();
+        self.summary.functions += 1;
+        self.naming.renaming_counts.entry(name).or_default();
+        if let Some(program) = self.current.program_id {
+            self.functions.processing_order.push(QualifiedName { program: program.into(), function: name });
+        }
+        if function.finalize.is_some() {
+            self.functions.finalize_functions.insert(function.identifier.name);
+        }
+
+        if let Some(observer) = &mut self.observer {
+            observer.enter_function(name);
+        }
+
         // Allocate a `RenameTable` for the function.
         self.push();
 
         // There is no need to reconstruct `function.inputs`.
         // However, for each input, we must add each symbol to the rename table.
-        for input_variable in function.input.iter() {
-            let identifier = input_variable.identifier();
-            self.rename_table.update(identifier.name, identifier.name, identifier.id);
-        }
+        self.register_inputs(&function.input);
+        self.functions
+            .signatures
+            .insert(name, FunctionSignature::new(&function.input, &function.output, &function.output_type));
+        self.record_source_names(&function.input, &function.block);
 
+        let input = count_statements(&function.block);
         let block =
             Block { span: function.block.span, id: function.block.id, statements: self.consume_block(function.block) };
+        self.functions.free_variables.insert(function.identifier.name, core::mem::take(&mut self.locals.free_names));
+        self.record_expansion(name, StatementExpansion { input, output: count_statements(&block) }, function.span);
 
-        // Remove the `RenameTable` for the function.
-        self.pop();
-
-        let finalize = function.finalize.map(|finalize| {
-            // Allocate a `RenameTable` for the finalize block.
-            self.push();
+        // Remove the `RenameTable` for the function, retaining it if requested.
+        let table = self.pop();
+        self.retain_rename_table(function.identifier.name, table, false);
 
-            // There is no need to reconstruct `finalize.inputs`.
-            // However, for each input, we must add each symbol to the rename table.
-            for input_variable in finalize.input.iter() {
-                let identifier = input_variable.identifier();
-                self.rename_table.update(identifier.name, identifier.name, identifier.id);
-            }
+        if let Some(observer) = &mut self.observer {
+            observer.leave_function(name);
+        }
 
-            let block = Block {
-                span: finalize.block.span,
-                id: finalize.block.id,
-                statements: self.consume_block(finalize.block),
-            };
-
-            // Remove the `RenameTable` for the finalize block.
-            self.pop();
-
-            Finalize {
-                identifier: finalize.identifier,
-                input: finalize.input,
-                output: finalize.output,
-                output_type: finalize.output_type,
-                block,
-                span: finalize.span,
-                id: finalize.id,
+        let finalize = function.finalize.map(|finalize| self.consume_finalize(name, finalize));
+        self.record_temporaries(name, self.locals.function_temporaries, function.span);
+        if let (Some(max), Some(exceeded)) = (self.options.max_rename_entries, self.stop.rename_limit_exceeded) {
+            if exceeded == name {
+                self.emit_err(StaticSingleAssignerError::rename_table_limit_exceeded(
+                    self.resolve(name),
+                    max,
+                    function.span,
+                ));
             }
-        });
+        }
 
         Function {
             annotations: function.annotations,
@@ -126,36 +361,65 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
     }
 }
 
-impl ProgramScopeConsumer for StaticSingleAssigner<'_> {
+impl<S: ScopeStack> ProgramScopeConsumer for StaticSingleAssigner<'_, S> {
     type Output = ProgramScope;
 
+    /// Reconstructs the structs and functions of the program scope.
+    /// The records of the program scope are ordered by the layout given by `options.layout_resolver`, if set.
+    /// Note that the constants of the program scope are not consumed, since loop unrolling propagates and removes them.
+    /// If constants are still present, e.g. when loop unrolling is skipped, they are passed through unchanged.
+    /// The structs that are not referenced are recorded, and removed if `options.unreferenced_structs` is `Prune`.
     fn consume_program_scope(&mut self, input: ProgramScope) -> Self::Output {
-        ProgramScope {
+        // Look up the layout targeted by the program scope, before its records are reordered.
+        self.enter_program_scope(input.program_id);
+
+        let (structs, functions) = match self.options.source_order {
+            true => self.consume_in_source_order(input.structs, input.functions),
+            false => (
+                input.structs.into_iter().map(|(i, s)| (i, self.consume_struct(s))).collect(),
+                input.functions.into_iter().map(|(i, f)| (i, self.consume_selected_function(f))).collect(),
+            ),
+        };
+
+        let mut scope = ProgramScope {
             program_id: input.program_id,
-            structs: input.structs.into_iter().map(|(i, s)| (i, self.consume_struct(s))).collect(),
+            structs,
             mappings: input.mappings,
-            functions: input.functions.into_iter().map(|(i, f)| (i, self.consume_function(f))).collect(),
+            functions,
             consts: input.consts,
             span: input.span,
+        };
+
+        // Find the structs that are not referenced by the consumed program scope.
+        let mut references = StructReferences::default();
+        references.visit_program_scope(&scope);
+        let unreferenced: IndexSet<Symbol> =
+            scope.structs.iter().map(|(name, _)| *name).filter(|name| !references.names.contains(name)).collect();
+        if self.options.unreferenced_structs == UnreferencedStructPolicy::Prune {
+            scope.structs.retain(|(name, _)| !unreferenced.contains(name));
         }
+        self.records.unreferenced_structs.insert(scope.program_id.name.name, unreferenced);
+
+        scope
     }
 }
 
-impl ProgramConsumer for StaticSingleAssigner<'_> {
+impl<S: ScopeStack> ProgramConsumer for StaticSingleAssigner<'_, S> {
     type Output = Program;
 
+    /// Consumes the program and its imports, where the imports of each program are consumed before its program scopes.
+    /// A program with more functions than `options.max_functions` is reported and returned unchanged.
     fn consume_program(&mut self, input: Program) -> Self::Output {
-        Program {
-            imports: input
-                .imports
-                .into_iter()
-                .map(|(name, (import, span))| (name, (self.consume_program(import), span)))
-                .collect(),
-            program_scopes: input
-                .program_scopes
-                .into_iter()
-                .map(|(name, scope)| (name, self.consume_program_scope(scope)))
-                .collect(),
+        if let Some(max) = self.options.max_functions {
+            let count = count_qualified_functions(&input);
+            if count > max {
+                let span = input.program_scopes.values().next().map_or_else(Span::default, |scope| scope.span);
+                // The accessors must not report the state of a previously consumed program.
+                self.reset();
+                self.emit_err(StaticSingleAssignerError::function_limit_exceeded(count, max, span));
+                return input;
+            }
         }
+        self.consume_imports_then_scopes(input, |assigner, scope| assigner.consume_program_scope(scope))
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{RenameTable, StaticSingleAssigner};
+use crate::{ScopeStack, StaticSingleAssigner, TimelineEntry};
 
 use leo_ast::{
     AccessExpression,
@@ -46,7 +46,7 @@ use leo_span::Symbol;
 use indexmap::IndexSet;
 use itertools::Itertools;
 
-impl StatementConsumer for StaticSingleAssigner<'_> {
+impl<S: ScopeStack> StatementConsumer for StaticSingleAssigner<'_, S> {
     type Output = Vec<Statement>;
 
     /// Consumes the expressions in an `AssertStatement`, returning the list of simplified statements.
@@ -89,6 +89,11 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         // First consume the right-hand-side of the assignment.
         let (value, mut statements) = self.consume_expression(assign.value);
 
+        // Check whether a constant input is being reassigned.
+        if let Expression::Identifier(identifier) = &assign.place {
+            self.check_constant_input_reassignment(identifier);
+        }
+
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
@@ -104,8 +109,37 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
     }
 
     /// Consumes a `Block`, flattening its constituent `ConditionalStatement`s.
+    /// A variable used before its definition is reported at the use, rather than left as an unmapped reference.
+    /// Note that the span and ID of the block are preserved by the caller, since the AST does not retain comments.
+    /// The deepest nesting of expressions in each statement is recorded before the statement is flattened.
+    /// If `options.record_coverage` is set, the kinds of each statement and its expressions are also tallied before it is flattened.
+    /// If `options.record_block_exits` is set, the variables in scope at the exit of the block are recorded against its ID.
     fn consume_block(&mut self, block: Block) -> Self::Output {
-        block.statements.into_iter().flat_map(|statement| self.consume_statement(statement)).collect()
+        let id = block.id;
+        let statements: Vec<Statement> = block
+            .statements
+            .into_iter()
+            .flat_map(|statement| {
+                self.record_nesting_depth(&statement);
+                self.record_coverage(&statement);
+                match self.options.record_timeline {
+                    false => self.consume_statement(statement),
+                    true => {
+                        // Record the renamings introduced by the statement, separately from those of the enclosing statement.
+                        let (id, span) = (statement.id(), statement.span());
+                        let outer = core::mem::take(&mut self.locals.renamings);
+                        let statements = self.consume_statement(statement);
+                        let renamings = core::mem::replace(&mut self.locals.renamings, outer);
+                        self.naming.timeline.push(TimelineEntry { id, span, renamings });
+                        statements
+                    }
+                }
+            })
+            .collect();
+        if self.options.record_block_exits {
+            self.record_block_exit(id);
+        }
+        statements
     }
 
     /// Consumes a `ConditionalStatement`, producing phi functions (assign statements) for variables written in the then-block and otherwise-block.
@@ -163,8 +197,9 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         }));
 
         // Compute the write set for the variables written in the then-block or otherwise-block.
-        let if_write_set: IndexSet<&Symbol> = IndexSet::from_iter(if_table.local_names());
-        let else_write_set: IndexSet<&Symbol> = IndexSet::from_iter(else_table.local_names());
+        let (if_names, else_names) = (if_table.local_names(), else_table.local_names());
+        let if_write_set: IndexSet<&Symbol> = IndexSet::from_iter(if_names.iter());
+        let else_write_set: IndexSet<&Symbol> = IndexSet::from_iter(else_names.iter());
         let write_set = if_write_set.union(&else_write_set);
 
         // For each variable in the write set, instantiate and add a phi function to the list of produced statements.
@@ -172,7 +207,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
             // Note that phi functions only need to be instantiated if the variable exists before the `ConditionalStatement`.
             if self.rename_table.lookup(**symbol).is_some() {
                 // Helper to lookup an and create an argument for the phi function.
-                let create_phi_argument = |table: &RenameTable, symbol: Symbol| {
+                let create_phi_argument = |table: &S, symbol: Symbol| {
                     let name =
                         *table.lookup(symbol).unwrap_or_else(|| panic!("Symbol {symbol} should exist in the program."));
                     let id = *table
@@ -182,7 +217,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                 };
 
                 // Create a new name for the variable written to in the `ConditionalStatement`.
-                let new_name = self.assigner.unique_symbol(symbol, "$");
+                let new_name = self.new_name(**symbol, conditional.span);
 
                 // Create the arguments for the phi function.
                 let if_true = create_phi_argument(&if_table, **symbol);
@@ -217,7 +252,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                 };
 
                 // Update the `RenameTable` with the new name of the variable.
-                self.rename_table.update(*(*symbol), new_name, id);
+                self.rename(**symbol, new_name, id);
 
                 // Create a new `AssignStatement` for the phi function.
                 let identifier = Identifier { name: new_name, span: Default::default(), id };
@@ -250,8 +285,14 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         self.is_lhs = true;
         match definition.place {
             Expression::Identifier(identifier) => {
+                // Check whether the identifier shadows a function input.
+                self.check_shadowed_input(&identifier);
+                self.check_pinned_input(&identifier);
+                // Bind the identifier, so that later uses are not free.
+                // Note that assigning to a variable does not bind it, since the variable may keep its previous value on another path.
+                self.locals.bound_names.insert(identifier.name);
                 // Add the identifier to the rename table.
-                self.rename_table.update(identifier.name, identifier.name, identifier.id);
+                self.update_rename_table(identifier.name, identifier.name, identifier.id);
                 // Rename the identifier.
                 let identifier = match self.consume_identifier(identifier).0 {
                     Expression::Identifier(identifier) => identifier,
@@ -264,8 +305,13 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                 let elements: Vec<Expression> = tuple.elements.into_iter().map(|element| {
                     match element {
                         Expression::Identifier(identifier) => {
+                            // Check whether the identifier shadows a function input.
+                            self.check_shadowed_input(&identifier);
+                            self.check_pinned_input(&identifier);
+                            // Bind the identifier, so that later uses are not free.
+                            self.locals.bound_names.insert(identifier.name);
                             // Add the identifier to the rename table.
-                            self.rename_table.update(identifier.name, identifier.name, identifier.id);
+                            self.update_rename_table(identifier.name, identifier.name, identifier.id);
                             // Rename the identifier.
                             let identifier = match self.consume_identifier(identifier).0 {
                                 Expression::Identifier(identifier) => identifier,
@@ -376,7 +422,9 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         statements
     }
 
-    // TODO: Error message
+    /// Loops are unrolled before this pass, so the AST has no `IterationStatement`s.
+    /// Each iteration is unrolled into a block in which the loop variable is replaced by a constant,
+    /// so the definitions in each iteration are renamed like any other definitions, and cannot leak into other iterations.
     fn consume_iteration(&mut self, _input: IterationStatement) -> Self::Output {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::RenameTable;
+
+use leo_ast::NodeID;
+use leo_span::Symbol;
+
+/// The stack of scopes used by the `StaticSingleAssigner` to track the new names of variables.
+/// The assigner is generic over this trait, so that alternative scoping strategies can be plugged into the pass.
+/// `RenameTable` is the default implementation.
+pub trait ScopeStack {
+    /// Pushes a new, empty scope onto the stack.
+    fn push(&mut self);
+
+    /// Pops the innermost scope off the stack.
+    /// Returns the stack as it was before popping, so that the new names in the popped scope can still be looked up.
+    fn pop(&mut self) -> Self;
+
+    /// Maps `symbol` to `new_symbol` in the innermost scope, recording `id` as the node ID of `new_symbol`.
+    fn update(&mut self, symbol: Symbol, new_symbol: Symbol, id: NodeID);
+
+    /// Looks up the new name of `symbol`, from the innermost scope outwards.
+    fn lookup(&self, symbol: Symbol) -> Option<&Symbol>;
+
+    /// Looks up the node ID of the new name `symbol`, from the innermost scope outwards.
+    fn lookup_id(&self, symbol: &Symbol) -> Option<&NodeID>;
+
+    /// Returns the symbols that were renamed in the innermost scope.
+    fn local_names(&self) -> Vec<Symbol>;
+
+    /// Returns the innermost scope alone, without the scopes beneath it.
+    fn into_innermost(self) -> Self;
+}
+
+impl ScopeStack for RenameTable {
+    fn push(&mut self) {
+        let parent = core::mem::take(self);
+        let threshold = parent.threshold();
+        *self = RenameTable::with_threshold(Some(Box::from(parent)), threshold);
+    }
+
+    fn pop(&mut self) -> Self {
+        let parent = self.parent.clone().unwrap_or_default();
+        core::mem::replace(self, *parent)
+    }
+
+    fn update(&mut self, symbol: Symbol, new_symbol: Symbol, id: NodeID) {
+        RenameTable::update(self, symbol, new_symbol, id)
+    }
+
+    fn lookup(&self, symbol: Symbol) -> Option<&Symbol> {
+        RenameTable::lookup(self, symbol)
+    }
+
+    fn lookup_id(&self, symbol: &Symbol) -> Option<&NodeID> {
+        RenameTable::lookup_id(self, symbol)
+    }
+
+    fn local_names(&self) -> Vec<Symbol> {
+        RenameTable::local_names(self).copied().collect()
+    }
+
+    fn into_innermost(mut self) -> Self {
+        self.parent = None;
+        self
+    }
+}
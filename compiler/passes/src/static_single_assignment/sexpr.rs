@@ -0,0 +1,374 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use leo_ast::{
+    AccessExpression,
+    AssertVariant,
+    Block,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConstDeclaration,
+    Expression,
+    Finalize,
+    Function,
+    Input,
+    Literal,
+    Output,
+    Program,
+    ProgramScope,
+    Statement,
+    Struct,
+    Type,
+    Variant,
+};
+
+use std::fmt;
+
+/// Returns the S-expression form of `program`, e.g. for verifiers that read S-expressions rather than JSON.
+/// Each program scope, struct, function, and finalize block is a list headed by its kind, and each block lists its statements.
+/// Variables are atoms named as in the program, so the output of the pass shows the renamed variables, e.g. `(assign a$1 (+ a b))`.
+/// The output depends only on the program, since imports and program scopes are kept in insertion order.
+pub fn to_sexpr(program: &Program) -> String {
+    program_form(program).to_string()
+}
+
+/// An S-expression. The elements of a list after the given number of leading elements are each placed on their own indented line.
+enum SExpr {
+    Atom(String),
+    List(Vec<SExpr>, usize),
+}
+
+impl SExpr {
+    fn atom(atom: impl fmt::Display) -> Self {
+        Self::Atom(atom.to_string())
+    }
+
+    /// Returns the string `string` as an atom, quoted and escaped.
+    fn string(string: impl fmt::Display) -> Self {
+        Self::Atom(format!("\"{}\"", string.to_string().replace('\\', "\\\\").replace('"', "\\\"")))
+    }
+
+    /// Returns `atom` as an atom, or as a string if it contains characters that delimit atoms, e.g. spaces or parentheses.
+    fn escaped(atom: impl fmt::Display) -> Self {
+        let atom = atom.to_string();
+        match atom.contains(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | '"' | ';' | '\\')) {
+            true => Self::string(atom),
+            false => Self::Atom(atom),
+        }
+    }
+
+    /// Returns a list with all `elements` on one line.
+    fn list(elements: Vec<SExpr>) -> Self {
+        Self::List(elements, usize::MAX)
+    }
+
+    /// Returns a list with the first `leading` elements on one line, and each remaining element on its own line.
+    fn multiline(leading: usize, elements: Vec<SExpr>) -> Self {
+        Self::List(elements, leading)
+    }
+
+    fn write(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        match self {
+            Self::Atom(atom) => f.write_str(atom),
+            Self::List(elements, leading) => {
+                f.write_str("(")?;
+                for (i, element) in elements.iter().enumerate() {
+                    match i {
+                        0 => {}
+                        i if i < *leading => f.write_str(" ")?,
+                        _ => write!(f, "\n{:width$}", "", width = indent + 2)?,
+                    }
+                    element.write(f, indent + 2)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl fmt::Display for SExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+fn program_form(program: &Program) -> SExpr {
+    let mut elements = vec![SExpr::atom("program")];
+    elements.extend(program.imports.iter().map(|(name, (import, _))| {
+        SExpr::multiline(2, vec![SExpr::atom("import"), SExpr::atom(name), program_form(import)])
+    }));
+    elements.extend(program.program_scopes.values().map(scope_form));
+    SExpr::multiline(1, elements)
+}
+
+fn scope_form(scope: &ProgramScope) -> SExpr {
+    let mut elements = vec![SExpr::atom("scope"), SExpr::atom(scope.program_id)];
+    elements.extend(scope.consts.iter().map(|(_, declaration)| const_form(declaration)));
+    elements.extend(scope.structs.iter().map(|(_, struct_)| struct_form(struct_)));
+    elements.extend(scope.mappings.iter().map(|(_, mapping)| {
+        SExpr::list(vec![
+            SExpr::atom("mapping"),
+            SExpr::atom(mapping.identifier),
+            type_form(&mapping.key_type),
+            type_form(&mapping.value_type),
+        ])
+    }));
+    elements.extend(scope.functions.iter().map(|(_, function)| function_form(function)));
+    SExpr::multiline(2, elements)
+}
+
+fn struct_form(struct_: &Struct) -> SExpr {
+    let kind = if struct_.is_record { "record" } else { "struct" };
+    let mut elements = vec![SExpr::atom(kind), SExpr::atom(struct_.identifier)];
+    elements.extend(
+        struct_
+            .members
+            .iter()
+            .map(|member| SExpr::list(vec![SExpr::atom(member.identifier), type_form(&member.type_)])),
+    );
+    SExpr::list(elements)
+}
+
+fn function_form(function: &Function) -> SExpr {
+    let kind = match function.variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    };
+    let mut elements = vec![
+        SExpr::atom(kind),
+        SExpr::atom(function.identifier),
+        inputs_form(&function.input),
+        outputs_form(&function.output),
+        block_form(&function.block),
+    ];
+    if let Some(finalize) = &function.finalize {
+        elements.push(finalize_form(finalize));
+    }
+    SExpr::multiline(4, elements)
+}
+
+fn finalize_form(finalize: &Finalize) -> SExpr {
+    SExpr::multiline(4, vec![
+        SExpr::atom("finalize"),
+        SExpr::atom(finalize.identifier),
+        inputs_form(&finalize.input),
+        outputs_form(&finalize.output),
+        block_form(&finalize.block),
+    ])
+}
+
+/// Returns the list of `inputs`, where each input lists its name, type, and mode, if it has one.
+fn inputs_form(inputs: &[Input]) -> SExpr {
+    SExpr::list(
+        inputs
+            .iter()
+            .map(|input| {
+                let mut elements = vec![SExpr::atom(input.identifier()), type_form(&input.type_())];
+                let mode = input.mode().to_string();
+                if !mode.is_empty() {
+                    elements.push(SExpr::atom(mode));
+                }
+                SExpr::list(elements)
+            })
+            .collect(),
+    )
+}
+
+fn outputs_form(outputs: &[Output]) -> SExpr {
+    SExpr::list(outputs.iter().map(|output| type_form(&output.type_())).collect())
+}
+
+/// Returns `type_` as an atom, or as a string if it contains spaces or parentheses, e.g. a tuple type.
+fn type_form(type_: &Type) -> SExpr {
+    SExpr::escaped(type_name(type_))
+}
+
+/// Returns the name of `type_` as spelled in Leo source, e.g. `bool` rather than the `boolean` of its `Display` implementation.
+fn type_name(type_: &Type) -> String {
+    match type_ {
+        Type::Boolean => "bool".to_string(),
+        Type::Array(array) => format!("[{}; {}]", type_name(array.element_type()), array.length()),
+        Type::Mapping(mapping) => format!("({} => {})", type_name(&mapping.key), type_name(&mapping.value)),
+        Type::Tuple(tuple) => format!("({})", tuple.elements().iter().map(type_name).collect::<Vec<_>>().join(",")),
+        type_ => type_.to_string(),
+    }
+}
+
+fn block_form(block: &Block) -> SExpr {
+    let mut elements = vec![SExpr::atom("block")];
+    elements.extend(block.statements.iter().map(statement_form));
+    SExpr::multiline(1, elements)
+}
+
+fn statement_form(statement: &Statement) -> SExpr {
+    match statement {
+        Statement::Assign(assign) => {
+            SExpr::list(vec![SExpr::atom("assign"), expression_form(&assign.place), expression_form(&assign.value)])
+        }
+        Statement::Block(block) => block_form(block),
+        Statement::Conditional(conditional) => conditional_form(conditional),
+        Statement::Definition(definition) => SExpr::list(vec![
+            SExpr::atom("define"),
+            expression_form(&definition.place),
+            type_form(&definition.type_),
+            expression_form(&definition.value),
+        ]),
+        Statement::Expression(statement) => {
+            SExpr::list(vec![SExpr::atom("expr"), expression_form(&statement.expression)])
+        }
+        Statement::Iteration(iteration) => SExpr::multiline(5, vec![
+            SExpr::atom(if iteration.inclusive { "for-inclusive" } else { "for" }),
+            SExpr::atom(iteration.variable),
+            type_form(&iteration.type_),
+            expression_form(&iteration.start),
+            expression_form(&iteration.stop),
+            block_form(&iteration.block),
+        ]),
+        Statement::Return(return_) => {
+            let mut elements = vec![SExpr::atom("return"), expression_form(&return_.expression)];
+            if let Some(arguments) = &return_.finalize_arguments {
+                let mut finalize = vec![SExpr::atom("finalize")];
+                finalize.extend(arguments.iter().map(expression_form));
+                elements.push(SExpr::list(finalize));
+            }
+            SExpr::list(elements)
+        }
+        Statement::Assert(assert) => match &assert.variant {
+            AssertVariant::Assert(expression) => assertion_form("assert", &[expression]),
+            AssertVariant::AssertEq(left, right) => assertion_form("assert-eq", &[left, right]),
+            AssertVariant::AssertNeq(left, right) => assertion_form("assert-neq", &[left, right]),
+        },
+        Statement::Console(console) => SExpr::list(vec![SExpr::atom("console"), match &console.function {
+            ConsoleFunction::Assert(expression) => assertion_form("assert", &[expression]),
+            ConsoleFunction::AssertEq(left, right) => assertion_form("assert-eq", &[left, right]),
+            ConsoleFunction::AssertNeq(left, right) => assertion_form("assert-neq", &[left, right]),
+        }]),
+        Statement::Const(declaration) => const_form(declaration),
+    }
+}
+
+/// Returns the assertion `kind` of `operands`, e.g. `(assert-eq a$1 b)`.
+fn assertion_form(kind: &str, operands: &[&Expression]) -> SExpr {
+    let mut elements = vec![SExpr::atom(kind)];
+    elements.extend(operands.iter().map(|operand| expression_form(operand)));
+    SExpr::list(elements)
+}
+
+fn const_form(declaration: &ConstDeclaration) -> SExpr {
+    SExpr::list(vec![
+        SExpr::atom("const"),
+        SExpr::atom(declaration.place),
+        type_form(&declaration.type_),
+        expression_form(&declaration.value),
+    ])
+}
+
+fn conditional_form(conditional: &ConditionalStatement) -> SExpr {
+    let mut elements = vec![SExpr::atom("if"), expression_form(&conditional.condition), block_form(&conditional.then)];
+    if let Some(otherwise) = &conditional.otherwise {
+        elements.push(statement_form(otherwise));
+    }
+    SExpr::multiline(2, elements)
+}
+
+fn expression_form(expression: &Expression) -> SExpr {
+    match expression {
+        Expression::Identifier(identifier) => SExpr::atom(identifier),
+        Expression::Literal(Literal::String(string, ..)) => SExpr::string(string),
+        // Other literals are atoms unless they contain delimiters, e.g. the coordinates of a group.
+        Expression::Literal(literal) => SExpr::escaped(literal),
+        Expression::Binary(binary) => {
+            SExpr::list(vec![SExpr::atom(binary.op), expression_form(&binary.left), expression_form(&binary.right)])
+        }
+        Expression::Unary(unary) => {
+            SExpr::list(vec![SExpr::atom(snake_case(&format!("{:?}", unary.op))), expression_form(&unary.receiver)])
+        }
+        Expression::Ternary(ternary) => SExpr::list(vec![
+            SExpr::atom("ternary"),
+            expression_form(&ternary.condition),
+            expression_form(&ternary.if_true),
+            expression_form(&ternary.if_false),
+        ]),
+        Expression::Call(call) => {
+            let mut elements = vec![SExpr::atom("call"), expression_form(&call.function)];
+            elements.extend(call.arguments.iter().map(expression_form));
+            SExpr::list(elements)
+        }
+        Expression::Cast(cast) => {
+            SExpr::list(vec![SExpr::atom("cast"), expression_form(&cast.expression), type_form(&cast.type_)])
+        }
+        Expression::Struct(struct_) => {
+            let mut elements = vec![SExpr::atom("struct"), SExpr::atom(struct_.name)];
+            elements.extend(struct_.members.iter().map(|member| {
+                let value = match &member.expression {
+                    Some(expression) => expression_form(expression),
+                    None => SExpr::atom(member.identifier),
+                };
+                SExpr::list(vec![SExpr::atom(member.identifier), value])
+            }));
+            SExpr::list(elements)
+        }
+        Expression::Tuple(tuple) => {
+            let mut elements = vec![SExpr::atom("tuple")];
+            elements.extend(tuple.elements.iter().map(expression_form));
+            SExpr::list(elements)
+        }
+        Expression::Array(array) => {
+            let mut elements = vec![SExpr::atom("array")];
+            elements.extend(array.elements.iter().map(expression_form));
+            SExpr::list(elements)
+        }
+        Expression::Unit(_) => SExpr::list(Vec::new()),
+        Expression::Access(access) => access_form(access),
+        Expression::Err(_) => SExpr::atom("error"),
+    }
+}
+
+/// Returns the form of `access`, headed by its kind, e.g. `(member a$1 b)` or `(tuple-access t$2 0)`.
+fn access_form(access: &AccessExpression) -> SExpr {
+    match access {
+        AccessExpression::Array(array) => {
+            SExpr::list(vec![SExpr::atom("array-access"), expression_form(&array.array), expression_form(&array.index)])
+        }
+        AccessExpression::AssociatedConstant(constant) => {
+            SExpr::list(vec![SExpr::atom("associated-constant"), type_form(&constant.ty), SExpr::atom(constant.name)])
+        }
+        AccessExpression::AssociatedFunction(function) => {
+            let mut elements =
+                vec![SExpr::atom("associated-function"), type_form(&function.ty), SExpr::atom(function.name)];
+            elements.extend(function.arguments.iter().map(expression_form));
+            SExpr::list(elements)
+        }
+        AccessExpression::Member(member) => {
+            SExpr::list(vec![SExpr::atom("member"), expression_form(&member.inner), SExpr::atom(member.name)])
+        }
+        AccessExpression::Tuple(tuple) => {
+            SExpr::list(vec![SExpr::atom("tuple-access"), expression_form(&tuple.tuple), SExpr::atom(&tuple.index)])
+        }
+    }
+}
+
+/// Returns `name`, e.g. `AbsWrapped`, in snake case, e.g. `abs_wrapped`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 2);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Input, Mode, Output, Type};
+use leo_span::Symbol;
+
+/// The inputs and outputs of a function or finalize block, e.g. for documenting the interface of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionSignature {
+    /// The name, mode, and type of each input, in order.
+    pub inputs: Vec<(Symbol, Mode, Type)>,
+    /// The mode and type of each output, in order.
+    pub outputs: Vec<(Mode, Type)>,
+    /// The type of the output, e.g. a tuple of the types of the outputs.
+    pub output_type: Type,
+}
+
+impl FunctionSignature {
+    /// Returns the signature with the given `inputs`, `outputs`, and `output_type`.
+    pub(crate) fn new(inputs: &[Input], outputs: &[Output], output_type: &Type) -> Self {
+        Self {
+            inputs: inputs.iter().map(|input| (input.identifier().name, input.mode(), input.type_())).collect(),
+            outputs: outputs.iter().map(|output| (output.mode(), output.type_())).collect(),
+            output_type: output_type.clone(),
+        }
+    }
+}
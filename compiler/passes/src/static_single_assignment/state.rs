@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    FieldHoist,
+    FunctionSignature,
+    QualifiedName,
+    RecordLayout,
+    RecordReordering,
+    RenameRecord,
+    ReservedFieldPositions,
+    StatementExpansion,
+    TimelineEntry,
+};
+
+use leo_ast::{Identifier, NodeID, ProgramId, Type};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The numbering state of a block, used by `NumberingScheme::PerBlock`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BlockFrame {
+    /// The position of the block among its siblings.
+    pub(crate) position: usize,
+    /// The number of child blocks entered thus far.
+    pub(crate) children: usize,
+    /// The number of names introduced in the block thus far.
+    pub(crate) names: usize,
+    /// The number of entries added to the scope of the block in the rename table.
+    pub(crate) entries: usize,
+}
+
+/// The position of the pass in the program being consumed.
+#[derive(Default)]
+pub(crate) struct CurrentScope {
+    /// The names of the programs currently being consumed, from the root program to the innermost import.
+    pub(crate) import_path: Vec<Symbol>,
+    /// The program scope currently being consumed.
+    pub(crate) program_id: Option<ProgramId>,
+    /// The layout of the records of the program scope currently being consumed.
+    pub(crate) record_layout: RecordLayout,
+    /// The prefix of the names introduced in the program scope currently being consumed, if `options.prefix_scope_names` is set.
+    pub(crate) scope_prefix: Option<Symbol>,
+    /// The name of the function currently being consumed.
+    pub(crate) function: Option<Symbol>,
+}
+
+impl CurrentScope {
+    /// Leaves the program being consumed, restoring the layout of the records to `record_layout`.
+    pub(crate) fn reset(&mut self, record_layout: RecordLayout) {
+        *self = Self { record_layout, ..Default::default() };
+    }
+}
+
+/// The state of the function or finalize block currently being consumed, which is cleared as the pass enters each function.
+#[derive(Default)]
+pub(crate) struct LocalState {
+    /// The identifiers of the inputs of the function or finalize block currently being consumed.
+    pub(crate) input_identifiers: IndexMap<Symbol, Identifier>,
+    /// The names of the constant inputs of the function or finalize block currently being consumed.
+    pub(crate) constant_inputs: IndexSet<Symbol>,
+    /// The names chosen by the rename hook in the function or finalize block currently being consumed.
+    pub(crate) chosen_names: IndexSet<Symbol>,
+    /// The names in the source of the function or finalize block currently being consumed, if `options.valid_identifiers` is set.
+    pub(crate) source_names: IndexMap<Symbol, Span>,
+    /// The names defined or assigned in the function or finalize block currently being consumed.
+    pub(crate) defined_names: IndexSet<Symbol>,
+    /// The names in the source of the function or finalize block currently being consumed, including its inputs.
+    pub(crate) variables: IndexSet<Symbol>,
+    /// The original names defined thus far in the block currently being consumed.
+    pub(crate) bound_names: IndexSet<Symbol>,
+    /// The original names read before being defined in the block currently being consumed.
+    pub(crate) free_names: IndexSet<Symbol>,
+    /// The numbering state of the enclosing blocks, from the outermost to the innermost.
+    pub(crate) blocks: Vec<BlockFrame>,
+    /// The number of outermost blocks entered in the function currently being consumed.
+    pub(crate) root_blocks: usize,
+    /// The current depth of the scope stack.
+    pub(crate) scope_depth: usize,
+    /// The number of temporaries introduced thus far in the function currently being consumed, including its finalize block.
+    pub(crate) function_temporaries: usize,
+    /// The renamings introduced by the statement currently being consumed.
+    pub(crate) renamings: Vec<(Symbol, Symbol)>,
+}
+
+impl LocalState {
+    /// Clears the state.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The renamings introduced while consuming the most recent program.
+pub(crate) struct NamingReports<S> {
+    /// The renamings introduced while consuming the most recent program, in order.
+    pub(crate) history: Vec<RenameRecord>,
+    /// A mapping from each new name to the original name it renames, where each input maps to itself.
+    pub(crate) origins: IndexMap<Symbol, Symbol>,
+    /// A mapping from the names of the consumed functions to the number of renamings introduced in them.
+    pub(crate) renaming_counts: IndexMap<Symbol, usize>,
+    /// The renamings introduced by each consumed statement, if `options.record_timeline` is set.
+    pub(crate) timeline: Vec<TimelineEntry>,
+    /// The final `RenameTable`s of the consumed function blocks, if `options.retain_rename_tables` is set.
+    pub(crate) rename_tables: IndexMap<Symbol, S>,
+    /// The final `RenameTable`s of the consumed finalize blocks, if `options.retain_rename_tables` is set.
+    pub(crate) finalize_rename_tables: IndexMap<Symbol, S>,
+    /// A mapping from the IDs of the consumed blocks to the new names of the variables in scope at their exit, if `options.record_block_exits` is set.
+    pub(crate) block_exits: IndexMap<NodeID, IndexMap<Symbol, Symbol>>,
+}
+
+impl<S> Default for NamingReports<S> {
+    fn default() -> Self {
+        Self {
+            history: Vec::new(),
+            origins: IndexMap::new(),
+            renaming_counts: IndexMap::new(),
+            timeline: Vec::new(),
+            rename_tables: IndexMap::new(),
+            finalize_rename_tables: IndexMap::new(),
+            block_exits: IndexMap::new(),
+        }
+    }
+}
+
+impl<S> NamingReports<S> {
+    /// Clears the reports.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// What the pass collects about each function consumed in the most recent program.
+#[derive(Default)]
+pub(crate) struct FunctionReports {
+    /// The names of the functions with a finalize block, including those in imported programs.
+    pub(crate) finalize_functions: IndexSet<Symbol>,
+    /// A mapping from the names of the consumed functions to their signatures.
+    pub(crate) signatures: IndexMap<Symbol, FunctionSignature>,
+    /// A mapping from the names of the consumed functions to the signatures of their finalize blocks.
+    pub(crate) finalize_signatures: IndexMap<Symbol, FunctionSignature>,
+    /// A mapping from the names of the consumed functions to the variables defined in their finalize blocks.
+    pub(crate) finalize_definitions: IndexMap<Symbol, Vec<RenameRecord>>,
+    /// A mapping from the names of the consumed functions to the free variables of their blocks.
+    pub(crate) free_variables: IndexMap<Symbol, IndexSet<Symbol>>,
+    /// A mapping from the names of the consumed functions to the expansion of their blocks.
+    pub(crate) expansions: IndexMap<Symbol, StatementExpansion>,
+    /// A mapping from the names of the consumed functions to the deepest nesting of expressions in their source.
+    pub(crate) nesting_depths: IndexMap<Symbol, usize>,
+    /// A mapping from the names of the consumed functions to the number of temporaries introduced in them.
+    pub(crate) temporary_counts: IndexMap<Symbol, usize>,
+    /// The qualified names of the functions consumed in the most recent program, in the order in which they were consumed.
+    pub(crate) processing_order: Vec<QualifiedName>,
+}
+
+impl FunctionReports {
+    /// Clears the reports.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// What the pass collects about each record consumed in the most recent program.
+#[derive(Default)]
+pub(crate) struct RecordReports {
+    /// A mapping from the names of the consumed records to the order of their fields given by the layout.
+    pub(crate) canonical_orders: IndexMap<Symbol, Vec<Symbol>>,
+    /// A mapping from the names of the consumed records to the index in the output of the member at each index in the source.
+    pub(crate) member_indices: IndexMap<Symbol, Vec<usize>>,
+    /// A mapping from the names of the consumed records to the names and types of their fields, in the order of the output.
+    pub(crate) field_types: IndexMap<Symbol, Vec<(Symbol, Type)>>,
+    /// The reserved fields moved while consuming the most recent program, in order.
+    pub(crate) hoists: Vec<FieldHoist>,
+    /// The positions of the `owner` and `gates` fields declared by each record of the most recent program, before reordering, in order.
+    pub(crate) reserved_positions: Vec<ReservedFieldPositions>,
+    /// A mapping from the names of the consumed program scopes to the names of their unreferenced structs.
+    pub(crate) unreferenced_structs: IndexMap<Symbol, IndexSet<Symbol>>,
+}
+
+impl RecordReports {
+    /// Clears the reports.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The conditions under which the pass stops consuming the most recent program.
+#[derive(Default)]
+pub(crate) struct StopState {
+    /// Whether the deadline of the pass has passed while consuming the most recent program.
+    pub(crate) timed_out: bool,
+    /// The number of entries in the scopes of the rename table on the stack, counted if `options.max_rename_entries` is set.
+    pub(crate) rename_entries: usize,
+    /// The function in which the rename table first exceeded `options.max_rename_entries` in the most recent program, if any.
+    pub(crate) rename_limit_exceeded: Option<Symbol>,
+    /// The first record of the most recent program that needed reordering, if `options.abort_on_reordering` is set.
+    pub(crate) first_reordering: Option<RecordReordering>,
+}
+
+impl StopState {
+    /// Clears the state.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -14,11 +14,120 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
+use crate::{
+    checksum,
+    count_program_statements,
+    function_checksum,
+    qualified_functions,
+    visit_qualified_functions,
+    Assigner,
+    BlockFrame,
+    CollisionChecker,
+    ControlFlowDump,
+    CoverageCounter,
+    CurrentScope,
+    FieldHoist,
+    FunctionChecksums,
+    FunctionReports,
+    FunctionSignature,
+    LocalState,
+    MutationFinder,
+    MutationPoint,
+    NameCollector,
+    NamingReports,
+    NestingDepth,
+    NodeCoverage,
+    NumberingScheme,
+    ProgressEvent,
+    ProgressReporter,
+    QualifiedName,
+    QualifiedRecord,
+    RecordReordering,
+    RecordReports,
+    RenameRecord,
+    RenameTable,
+    ReservedFieldPositions,
+    ScopeCache,
+    ScopeStack,
+    SsaImportTree,
+    SsaObserver,
+    SsaOptions,
+    SsaSummary,
+    SsaValidator,
+    SsaViolation,
+    StatementExpansion,
+    StopState,
+    SymbolTable,
+    TimelineEntry,
+    TypeTable,
+    DEFAULT_EXPANSION_FACTOR,
+};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{
+    Block,
+    Expression,
+    Finalize,
+    Function,
+    FunctionConsumer,
+    Identifier,
+    Input,
+    Mode,
+    Node,
+    NodeBuilder,
+    NodeID,
+    Program,
+    ProgramConsumer,
+    ProgramId,
+    ProgramScope,
+    ProgramScopeConsumer,
+    ProgramVisitor,
+    Statement,
+    StatementConsumer,
+    StatementVisitor,
+    Struct,
+    StructConsumer,
+    Type,
+};
+use leo_errors::{emitter::Handler, StaticSingleAssignerError, StaticSingleAssignerWarning};
+use leo_span::{Span, Symbol};
 
-pub struct StaticSingleAssigner<'a> {
+use indexmap::{IndexMap, IndexSet};
+use std::{borrow::Cow, fmt::Display, sync::mpsc::Sender, time::Instant};
+
+/// A program whose imports are being consumed, used to traverse the imports without recursion.
+struct ImportFrame {
+    /// The name and span of the import, or `None` for the root program.
+    import: Option<(Symbol, Span)>,
+    /// The imports of the program that remain to be consumed.
+    pending: indexmap::map::IntoIter<Symbol, (Program, Span)>,
+    /// The imports of the program consumed thus far.
+    imports: IndexMap<Symbol, (Program, Span)>,
+    /// The program scopes of the program, consumed once all of its imports are consumed.
+    program_scopes: IndexMap<Symbol, ProgramScope>,
+}
+
+impl ImportFrame {
+    /// Returns a new `ImportFrame` for `program`, imported under `import` unless it is the root program.
+    fn new(import: Option<(Symbol, Span)>, program: Program) -> Self {
+        Self {
+            import,
+            pending: program.imports.into_iter(),
+            imports: IndexMap::new(),
+            program_scopes: program.program_scopes,
+        }
+    }
+}
+
+/// Converts programs into static single assignment form, tracking the new names of variables with a `ScopeStack`.
+/// The scope stack defaults to a `RenameTable`; use `StaticSingleAssigner::with_scope_stack` to provide another implementation.
+///
+/// An assigner cannot be shared between threads: it is neither `Send` nor `Sync`, since it holds the state of the program being consumed
+/// and reports through a `Handler`, which is not thread safe. Moreover, symbols are interned per thread, so each thread compiling programs
+/// concurrently needs its own session, in which it builds its own `Assigner`, tables, and `StaticSingleAssigner`.
+/// The `SsaOptions` are cheap to clone and can be sent to other threads, but the symbols they hold, e.g. `focus`, are interned in the session
+/// of the thread that built them, so options holding symbols should be built in each thread.
+/// Assigners in different threads do not interact, so each produces the same output as consuming its program sequentially.
+pub struct StaticSingleAssigner<'a, S: ScopeStack = RenameTable> {
     /// A counter used to generate unique node IDs.
     pub(crate) node_builder: &'a NodeBuilder,
     /// The `SymbolTable` of the program.
@@ -26,45 +135,1100 @@ pub struct StaticSingleAssigner<'a> {
     /// A mapping from node IDs to their types.
     pub(crate) type_table: &'a TypeTable,
     /// The `RenameTable` for the current basic block in the AST
-    pub(crate) rename_table: RenameTable,
+    pub(crate) rename_table: S,
     /// A flag to determine whether or not the traversal is on the left-hand side of a definition or an assignment.
     pub(crate) is_lhs: bool,
     /// A struct used to construct (unique) assignment statements.
     pub(crate) assigner: &'a Assigner,
+    /// An error handler used for any diagnostics emitted during the pass.
+    /// The `Emitter` of the handler controls whether diagnostics are collected, printed, or suppressed.
+    pub(crate) handler: &'a Handler,
+    /// The options configuring the pass.
+    pub(crate) options: SsaOptions,
+    /// The position of the pass in the program being consumed.
+    pub(crate) current: CurrentScope,
+    /// The state of the function currently being consumed.
+    pub(crate) locals: LocalState,
+    /// The renamings introduced while consuming the most recent program.
+    pub(crate) naming: NamingReports<S>,
+    /// What the pass collects about the functions of the most recent program.
+    pub(crate) functions: FunctionReports,
+    /// What the pass collects about the records of the most recent program.
+    pub(crate) records: RecordReports,
+    /// The conditions under which the pass stops consuming the most recent program.
+    pub(crate) stop: StopState,
+    /// The summary of the transformations applied thus far.
+    pub(crate) summary: SsaSummary,
+    /// The tree of imports traversed while consuming the most recent program.
+    pub(crate) import_graph: SsaImportTree,
+    /// An observer notified as each function is consumed, if one is set.
+    pub(crate) observer: Option<Box<dyn SsaObserver + 'a>>,
+    /// The kinds of the statements and expressions consumed in the most recent program, if `options.record_coverage` is set.
+    pub(crate) coverage: NodeCoverage,
 }
 
 impl<'a> StaticSingleAssigner<'a> {
     /// Initializes a new `StaticSingleAssigner` with an empty `RenameTable`.
-    pub(crate) fn new(
+    /// Diagnostics are reported through `handler`; use `Handler::new_with_buf` to collect them into a buffer.
+    pub fn new(
+        node_builder: &'a NodeBuilder,
+        symbol_table: &'a SymbolTable,
+        type_table: &'a TypeTable,
+        assigner: &'a Assigner,
+        handler: &'a Handler,
+        options: SsaOptions,
+    ) -> Self {
+        let threshold = options.rename_table_threshold.unwrap_or(RenameTable::DEFAULT_THRESHOLD);
+        Self::with_scope_stack(
+            node_builder,
+            symbol_table,
+            type_table,
+            assigner,
+            handler,
+            options,
+            RenameTable::with_threshold(None, threshold),
+        )
+    }
+}
+
+impl<'a, S: ScopeStack> StaticSingleAssigner<'a, S> {
+    /// Initializes a new `StaticSingleAssigner` that tracks the new names of variables with `scope_stack`.
+    /// Note that `options.rename_table_threshold` only applies to the default `RenameTable`.
+    pub fn with_scope_stack(
         node_builder: &'a NodeBuilder,
         symbol_table: &'a SymbolTable,
         type_table: &'a TypeTable,
         assigner: &'a Assigner,
+        handler: &'a Handler,
+        options: SsaOptions,
+        scope_stack: S,
     ) -> Self {
-        Self { node_builder, symbol_table, type_table, rename_table: RenameTable::new(None), is_lhs: false, assigner }
+        Self {
+            node_builder,
+            symbol_table,
+            type_table,
+            rename_table: scope_stack,
+            is_lhs: false,
+            assigner,
+            handler,
+            current: CurrentScope { record_layout: options.record_layout.clone(), ..Default::default() },
+            options,
+            locals: LocalState::default(),
+            naming: NamingReports::default(),
+            functions: FunctionReports::default(),
+            records: RecordReports::default(),
+            stop: StopState::default(),
+            summary: SsaSummary::default(),
+            import_graph: SsaImportTree::default(),
+            observer: None,
+            coverage: NodeCoverage::default(),
+        }
+    }
+
+    /// Returns the number of statements in the block of the consumed function named `function`, before and after the pass.
+    pub fn expansion(&self, function: Symbol) -> Option<StatementExpansion> {
+        self.functions.expansions.get(&function).copied()
+    }
+
+    /// Returns the signature of the consumed function named `function`.
+    pub fn signature(&self, function: Symbol) -> Option<&FunctionSignature> {
+        self.functions.signatures.get(&function)
+    }
+
+    /// Returns the signature of the finalize block of the consumed function named `function`.
+    pub fn finalize_signature(&self, function: Symbol) -> Option<&FunctionSignature> {
+        self.functions.finalize_signatures.get(&function)
+    }
+
+    /// Returns the variables defined in the finalize block of the consumed function named `function`, with their new names, in order.
+    /// Each version of a variable is listed, including those defined by phi functions, e.g. to allocate a storage slot for each.
+    /// Note that the inputs of the finalize block are not definitions, and the temporaries introduced by the pass are not listed.
+    pub fn finalize_definitions(&self, function: Symbol) -> Option<&[RenameRecord]> {
+        self.functions.finalize_definitions.get(&function).map(Vec::as_slice)
+    }
+
+    /// Returns the order of the fields of the consumed record named `record` given by its record layout.
+    /// This is the order of the fields in the output, unless `options.preserve_member_order` is set.
+    pub fn canonical_order(&self, record: Symbol) -> Option<&[Symbol]> {
+        self.records.canonical_orders.get(&record).map(Vec::as_slice)
+    }
+
+    /// Returns the index in the output of each member of the consumed record named `record`, indexed by the position of the member in the source,
+    /// e.g. for a backend that accesses fields by index. A record that is left in source order maps each index to itself.
+    pub fn member_indices(&self, record: Symbol) -> Option<&[usize]> {
+        self.records.member_indices.get(&record).map(Vec::as_slice)
+    }
+
+    /// Returns the names and types of the fields of the consumed record named `record`, in the order of the output,
+    /// e.g. to order the record definitions by the types they depend on.
+    pub fn field_types(&self, record: Symbol) -> Option<&[(Symbol, Type)]> {
+        self.records.field_types.get(&record).map(Vec::as_slice)
+    }
+
+    /// Returns the kinds of the statements and expressions consumed in the most recent program, if `options.record_coverage` is set.
+    /// Note that the statements introduced by the pass, e.g. the assignments to temporaries, are not counted.
+    pub fn coverage(&self) -> &NodeCoverage {
+        &self.coverage
+    }
+
+    /// Returns the first record of the most recently consumed program that needed reordering, if `options.abort_on_reordering` is set.
+    pub fn first_reordering(&self) -> Option<&RecordReordering> {
+        self.stop.first_reordering.as_ref()
+    }
+
+    /// Returns the deepest nesting of expressions in the source of the consumed function named `function`, including its finalize block.
+    /// Since each nested expression is flattened into a statement, the depth indicates how much the pass expands the function.
+    pub fn nesting_depth(&self, function: Symbol) -> Option<usize> {
+        self.functions.nesting_depths.get(&function).copied()
+    }
+
+    /// Returns the variables in scope at the exit of the consumed block with ID `id`, mapped to their new names, if `options.record_block_exits` is set.
+    /// The variables include the inputs of the enclosing function, but not the temporaries introduced by the pass.
+    /// Note that the phi functions of a conditional statement are in the enclosing block, so the names in a branch are those of the branch.
+    pub fn block_exit(&self, id: NodeID) -> Option<&IndexMap<Symbol, Symbol>> {
+        self.naming.block_exits.get(&id)
+    }
+
+    /// Records the variables in scope at the exit of the block with ID `id`, which is the current scope.
+    /// Since each variable has been renamed in or before the block, the variables are those of `origins` that are in scope.
+    pub(crate) fn record_block_exit(&mut self, id: NodeID) {
+        let variables: IndexSet<Symbol> = self.naming.origins.values().copied().collect();
+        let exit = variables
+            .into_iter()
+            .filter_map(|variable| self.rename_table.lookup(variable).map(|name| (variable, *name)))
+            .collect();
+        self.naming.block_exits.insert(id, exit);
+    }
+
+    /// Returns the number of renamings introduced in the consumed function named `function`, including its finalize block.
+    /// Note that the temporaries introduced for nested expressions are not renamings.
+    pub fn renaming_count(&self, function: Symbol) -> Option<usize> {
+        self.naming.renaming_counts.get(&function).copied()
+    }
+
+    /// Returns the number of temporaries introduced in the consumed function named `function`, including its finalize block.
+    pub fn temporary_count(&self, function: Symbol) -> Option<usize> {
+        self.functions.temporary_counts.get(&function).copied()
+    }
+
+    /// Records the number of temporaries introduced in `function`, warning if it exceeds `options.temporary_warning_threshold`.
+    pub(crate) fn record_temporaries(&mut self, function: Symbol, count: usize, span: Span) {
+        self.functions.temporary_counts.insert(function, count);
+        if let Some(threshold) = self.options.temporary_warning_threshold {
+            if count > threshold {
+                self.emit_warning(StaticSingleAssignerWarning::temporary_threshold_exceeded(
+                    self.resolve(function),
+                    count,
+                    threshold,
+                    span,
+                ));
+            }
+        }
+    }
+
+    /// Returns the names of the consumed functions in which no renamings were introduced, in the order in which they were consumed.
+    /// Such functions, e.g. passthroughs, are candidates for cheap inlining.
+    pub fn unrenamed_functions(&self) -> IndexSet<Symbol> {
+        self.naming.renaming_counts.iter().filter(|(_, count)| **count == 0).map(|(function, _)| *function).collect()
+    }
+
+    /// Returns the qualified names of the functions consumed in the most recent program, in the order in which they were consumed.
+    /// The functions of each import precede those of its importer, and the functions that are not selected, e.g. out of focus, are not listed.
+    pub fn processing_order(&self) -> &[QualifiedName] {
+        &self.functions.processing_order
+    }
+
+    /// Returns the names of the structs of the consumed program scope named `program`, e.g. `test`,
+    /// that are not referenced by any function, mapping, or other struct of the scope.
+    /// Note that the structs are returned even if `options.unreferenced_structs` removed them from the output.
+    pub fn unreferenced_structs(&self, program: Symbol) -> Option<&IndexSet<Symbol>> {
+        self.records.unreferenced_structs.get(&program)
+    }
+
+    /// Returns an estimate of the number of statements in the output of the pass on `program`, without transforming it.
+    /// The estimate is the number of input statements scaled by `options.expansion_factor`, so it is cheap to compute,
+    /// e.g. to pre-allocate before consuming the program, but it is not exact.
+    pub fn estimate_output_size(&self, program: &Program) -> usize {
+        let factor = self.options.expansion_factor.unwrap_or(DEFAULT_EXPANSION_FACTOR);
+        (count_program_statements(program) as f64 * factor).ceil() as usize
+    }
+
+    /// Checks whether `program` is already in static single assignment form, without transforming it.
+    /// Returns the violations found, in program order, using the record layouts given by the options of the pass.
+    /// Note that no diagnostics are emitted, so this can be used to check the output of `consume_program`.
+    pub fn validate(&self, program: &Program) -> Vec<SsaViolation> {
+        let mut validator = SsaValidator::new(&self.options);
+        validator.visit_program(program);
+        validator.violations
+    }
+
+    /// Enumerates the points at which a mutant could be injected into `program`, the output of the most recent `consume_program`.
+    /// Each use of a variable could be replaced by another version of it, and the arguments of each phi function could be swapped,
+    /// e.g. so that a mutation-testing harness can inject each mutant and check that the test suite catches it.
+    pub fn mutation_points(&self, program: &Program) -> Vec<MutationPoint> {
+        let mut finder = MutationFinder::new(&self.naming.origins);
+        finder.visit_program(program);
+        finder.points
+    }
+
+    /// Renders `program`, the output of the most recent `consume_program`, with its blocks indented by control flow.
+    /// Each block is annotated with its scope depth and the renamings it introduces, e.g. `then block at depth 2: b -> b$1`,
+    /// and the phi functions merging the branches of a conditional follow it in the enclosing block.
+    pub fn control_flow_dump(&self, program: &Program) -> String {
+        let mut dump = ControlFlowDump::new(&self.naming.origins);
+        dump.dump_program(program);
+        dump.output
+    }
+
+    /// Returns the source name that `symbol` renames in the most recent program, e.g. `a` for `a$3`, for source-level debugging.
+    /// Inputs keep their names and resolve to themselves, while the temporaries introduced by the pass have no source name.
+    pub fn original_name(&self, symbol: Symbol) -> Option<Symbol> {
+        self.naming.origins.get(&symbol).copied()
+    }
+
+    /// Returns `true` if `first` and `second` are versions of the same original name, e.g. `a$3` and `a$7`.
+    /// Note that a name without a source name, e.g. a temporary, is a version of itself.
+    pub fn is_same_original(&self, first: Symbol, second: Symbol) -> bool {
+        self.original_name(first).unwrap_or(first) == self.original_name(second).unwrap_or(second)
+    }
+
+    /// Sets the observer notified as each function is consumed, replacing any previous observer.
+    pub fn set_observer(&mut self, observer: impl SsaObserver + 'a) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Consumes `program`, returning the program in SSA form along with a summary of the applied transformations.
+    pub fn consume_program_with_summary(&mut self, program: Program) -> (Program, SsaSummary) {
+        let program = self.consume_program(program);
+        (program, core::mem::take(&mut self.summary))
+    }
+
+    /// Consumes `program`, sending a `ProgressEvent` to `sender` as each function and finalize block is consumed.
+    /// The total number of functions is that of the functions selected by `options.focus` and `options.target_filter`,
+    /// including those of the imports, where the functions of a program imported more than once are counted once per import.
+    /// Note that the functions skipped once the pass stops, e.g. at the deadline, are still counted.
+    /// Any observer set with `set_observer` is not notified while the program is consumed, and is restored afterwards.
+    pub fn consume_program_with_progress(&mut self, program: Program, sender: Sender<ProgressEvent>) -> Program {
+        let mut total = 0;
+        visit_qualified_functions(&program, |_, function| total += usize::from(self.is_selected(function)));
+        let observer = self.observer.replace(Box::new(ProgressReporter::new(sender, total)));
+        let program = self.consume_program(program);
+        self.observer = observer;
+        program
+    }
+
+    /// Consumes only the records of `program` and its transitive imports, returning each record in its canonical layout.
+    /// The functions are not consumed, so this is cheap enough to extract the records, e.g. to generate bindings for other languages.
+    /// The records of each program follow those of its imports, and a program imported more than once is listed once.
+    pub fn consume_records(&mut self, program: Program) -> IndexMap<QualifiedRecord, Struct> {
+        let mut records = IndexMap::new();
+        self.consume_imports_then_scopes(program, |assigner, scope| {
+            for (name, struct_) in scope.structs.iter().filter(|(_, struct_)| struct_.is_record) {
+                let record = QualifiedRecord { program: scope.program_id.into(), record: *name };
+                if !records.contains_key(&record) {
+                    records.insert(record, assigner.consume_struct(struct_.clone()));
+                }
+            }
+            scope
+        });
+        records
+    }
+
+    /// Consumes `program`, returning the program in SSA form along with the reserved fields moved in its records and those of its imports.
+    /// The fields are listed in the order in which the records are consumed, and canonical records contribute no fields.
+    /// Note that no fields are moved if `options.preserve_member_order` is set.
+    pub fn consume_program_with_hoists(&mut self, program: Program) -> (Program, Vec<FieldHoist>) {
+        let program = self.consume_program(program);
+        (program, self.records.hoists.clone())
+    }
+
+    /// Consumes `program`, returning the program in SSA form along with the positions of the `owner` and `gates` fields in the source of each record.
+    /// Unlike the hoists, every record of the program and its imports is listed, including the canonical ones, in the order in which they are consumed.
+    pub fn consume_program_with_reserved_positions(
+        &mut self,
+        program: Program,
+    ) -> (Program, Vec<ReservedFieldPositions>) {
+        let program = self.consume_program(program);
+        (program, self.records.reserved_positions.clone())
+    }
+
+    /// Consumes `program`, reusing the output in `cache` for each program scope that is not affected by the change to the program named `changed`.
+    /// The affected scopes are those of `changed` and its dependents in `graph`, e.g. the import graph of the previous consumption.
+    /// Returns the program in SSA form along with the names of the programs whose scopes were consumed, in order, and caches their output.
+    /// A scope that is not cached is consumed, so the first call with an empty cache consumes the whole program.
+    /// Note that the summary and the other collected state only reflect the consumed scopes.
+    pub fn consume_program_incrementally(
+        &mut self,
+        program: Program,
+        changed: Symbol,
+        graph: &SsaImportTree,
+        cache: &mut ScopeCache,
+    ) -> (Program, IndexSet<Symbol>) {
+        let affected = graph.dependents(changed);
+        let mut consumed = IndexSet::new();
+        let program = self.consume_imports_then_scopes(program, |assigner, scope| {
+            let name = scope.program_id.name.name;
+            if !affected.contains(&name) {
+                if let Some(cached) = cache.get(name) {
+                    return cached.clone();
+                }
+            }
+            let scope = assigner.consume_program_scope(scope);
+            cache.insert(name, scope.clone());
+            consumed.insert(name);
+            scope
+        });
+        (program, consumed)
+    }
+
+    /// Consumes `program`, returning the program in SSA form along with a smaller program reproducing the errors, if any were reported.
+    /// The reproduction keeps the structs, mappings, and imports of `program`, and is found by consuming first none of its functions, then each function alone.
+    /// The first candidate that reports an error is returned, or `program` itself if no candidate does, e.g. if the error needs several functions.
+    /// Each candidate is consumed by a fresh assigner with its own handler, so the candidates report nothing through the handler of this assigner.
+    pub fn consume_program_with_repro(&mut self, program: Program) -> (Program, Option<Program>) {
+        let errors = self.handler.err_count();
+        let output = self.consume_program(program.clone());
+        if self.handler.err_count() == errors {
+            return (output, None);
+        }
+
+        // Returns `program` with only the function named `function` in its program scopes, or no functions if it is `None`.
+        let candidate = |function: Option<Symbol>| {
+            let mut candidate = program.clone();
+            for scope in candidate.program_scopes.values_mut() {
+                scope.functions.retain(|(name, _)| Some(*name) == function);
+            }
+            candidate
+        };
+        let functions =
+            program.program_scopes.values().flat_map(|scope| scope.functions.iter().map(|(name, _)| Some(*name)));
+        let repro = core::iter::once(None).chain(functions).map(candidate).find(|candidate| {
+            let (handler, _) = Handler::new_with_buf();
+            StaticSingleAssigner::new(
+                self.node_builder,
+                self.symbol_table,
+                self.type_table,
+                self.assigner,
+                &handler,
+                self.options.clone(),
+            )
+            .consume_program(candidate.clone());
+            handler.had_errors()
+        });
+        (output, Some(repro.unwrap_or(program)))
+    }
+
+    /// Consumes `program`, returning the program in SSA form along with its `checksum`.
+    /// The checksum is stable across builds, so it can be compared to verify that the pass is deterministic.
+    pub fn consume_program_with_checksum(&mut self, program: Program) -> (Program, String) {
+        let program = self.consume_program(program);
+        let hash = checksum(&program);
+        (program, hash)
+    }
+
+    /// Consumes `program`, returning the program in SSA form along with the `FunctionChecksums` of each function of the program and its imports.
+    /// The checksums are keyed by the qualified names of the functions, so they do not depend on the order in which the functions are consumed.
+    /// Note that the SSA form of a function depends on the names introduced before it, so identical functions may have different `after` checksums.
+    pub fn consume_program_with_function_checksums(
+        &mut self,
+        program: Program,
+    ) -> (Program, IndexMap<QualifiedName, FunctionChecksums>) {
+        let before: IndexMap<QualifiedName, String> = qualified_functions(&program)
+            .into_iter()
+            .map(|(name, function)| (name, function_checksum(&function)))
+            .collect();
+        let program = self.consume_program(program);
+        let checksums = qualified_functions(&program)
+            .into_iter()
+            .filter_map(|(name, function)| {
+                let before = before.get(&name)?.clone();
+                Some((name, FunctionChecksums { before, after: function_checksum(&function) }))
+            })
+            .collect();
+        (program, checksums)
+    }
+
+    /// Consumes `program`, returning a copy of the original program along with the program in SSA form.
+    /// Note that this clones the entire program, so callers that do not need the original should use `consume_program`.
+    pub fn consume_program_paired(&mut self, program: Program) -> (Program, Program) {
+        let original = program.clone();
+        (original, self.consume_program(program))
+    }
+
+    /// Returns the names of the functions with a finalize block in the most recently consumed program, including its imports.
+    pub fn finalize_functions(&self) -> &IndexSet<Symbol> {
+        &self.functions.finalize_functions
+    }
+
+    /// Returns the free variables of the block of the consumed function named `function`, in the order in which they are first read.
+    /// For a well-formed function, these are exactly the inputs that the function reads.
+    pub fn free_variables(&self, function: Symbol) -> Option<&IndexSet<Symbol>> {
+        self.functions.free_variables.get(&function)
+    }
+
+    /// Returns the tree of imports traversed while consuming the most recent program.
+    /// Use `SsaImportTree::to_dot` to render it with Graphviz.
+    pub fn import_graph(&self) -> &SsaImportTree {
+        &self.import_graph
+    }
+
+    /// Returns the renamings introduced by each statement of the most recent program, if `options.record_timeline` is set.
+    /// The entries are ordered by the completion of their statements, so nested statements precede their parents.
+    pub fn timeline(&self) -> &[TimelineEntry] {
+        &self.naming.timeline
+    }
+
+    /// Returns the renamings introduced while consuming the most recent program, in order.
+    /// Use `rename_history_to_csv` to export the history, e.g. to aggregate statistics across programs.
+    pub fn rename_history(&self) -> &[RenameRecord] {
+        &self.naming.history
+    }
+
+    /// Returns the final `RenameTable` of the block of the function named `function`, if `options.retain_rename_tables` is set.
+    pub fn rename_table(&self, function: Symbol) -> Option<&S> {
+        self.naming.rename_tables.get(&function)
+    }
+
+    /// Returns the final `RenameTable` of the finalize block of the function named `function`, if `options.retain_rename_tables` is set.
+    pub fn finalize_rename_table(&self, function: Symbol) -> Option<&S> {
+        self.naming.finalize_rename_tables.get(&function)
+    }
+
+    /// Adds each of `inputs` to the current `RenameTable`, recording their spans and whether they are constant.
+    /// Note that the parser rejects const-generic parameters, so the inputs are the only names seeded into the table.
+    /// Constant inputs, which play the role of such parameters, are seeded with identity mappings like any other input.
+    /// Note that the AST has no methods, so there is no implicit receiver, e.g. `self`, to seed alongside the inputs.
+    pub(crate) fn register_inputs(&mut self, inputs: &[Input]) {
+        self.locals.input_identifiers.clear();
+        self.locals.constant_inputs.clear();
+        self.locals.chosen_names.clear();
+        for input_variable in inputs.iter() {
+            let identifier = input_variable.identifier();
+            self.update_rename_table(identifier.name, identifier.name, identifier.id);
+            self.locals.input_identifiers.insert(identifier.name, identifier);
+            self.naming.origins.insert(identifier.name, identifier.name);
+            if input_variable.mode() == Mode::Constant {
+                self.locals.constant_inputs.insert(identifier.name);
+            }
+        }
+    }
+
+    /// Records the names defined in a function or finalize block, used to detect the use of a variable before its definition.
+    /// If `options.valid_identifiers` is set, all names in the source are recorded, including the names of the inputs.
+    pub(crate) fn record_source_names(&mut self, inputs: &[Input], block: &Block) {
+        let mut collector = NameCollector::default();
+        for input in inputs.iter() {
+            let identifier = input.identifier();
+            collector.names.entry(identifier.name).or_insert(identifier.span);
+        }
+        collector.visit_block(block);
+        self.locals.defined_names = collector.defined;
+        self.locals.variables = collector.names.keys().copied().collect();
+        self.locals.source_names = match self.options.valid_identifiers {
+            true => collector.names,
+            false => IndexMap::new(),
+        };
+    }
+
+    /// Consumes the finalize block of the function named `function`, using a `RenameTable` of its own.
+    /// Note that the outputs of a function are unnamed, so a variable of the finalize block cannot shadow them: an external output
+    /// has an identifier, but the parser gives every external output the same placeholder name, which is not in scope in either block.
+    pub(crate) fn consume_finalize(&mut self, function: Symbol, finalize: Finalize) -> Finalize {
+        if let Some(observer) = &mut self.observer {
+            observer.enter_finalize(function);
+        }
+
+        // Allocate a `RenameTable` for the finalize block.
+        self.push();
+
+        // There is no need to reconstruct `finalize.inputs`.
+        // However, for each input, we must add each symbol to the rename table.
+        self.register_inputs(&finalize.input);
+        self.functions
+            .finalize_signatures
+            .insert(function, FunctionSignature::new(&finalize.input, &finalize.output, &finalize.output_type));
+        self.record_source_names(&finalize.input, &finalize.block);
+
+        // The free variables of the finalize block are not recorded.
+        self.locals.bound_names.clear();
+        let history = self.naming.history.len();
+        let block =
+            Block { span: finalize.block.span, id: finalize.block.id, statements: self.consume_block(finalize.block) };
+        // The renamings introduced by the finalize block are the last in the history.
+        self.functions.finalize_definitions.insert(function, self.naming.history[history..].to_vec());
+
+        // Remove the `RenameTable` for the finalize block, retaining it if requested.
+        let table = self.pop();
+        self.retain_rename_table(function, table, true);
+
+        if let Some(observer) = &mut self.observer {
+            observer.leave_finalize(function);
+        }
+
+        Finalize {
+            identifier: finalize.identifier,
+            input: finalize.input,
+            output: finalize.output,
+            output_type: finalize.output_type,
+            block,
+            span: finalize.span,
+            id: finalize.id,
+        }
+    }
+
+    /// Consumes only the finalize blocks of `program` and its imports, leaving all other blocks untouched.
+    pub fn consume_finalize_blocks(&mut self, program: Program) -> Program {
+        self.consume_imports_then_scopes(program, |assigner, scope| {
+            let functions = scope
+                .functions
+                .into_iter()
+                .map(|(name, function)| {
+                    assigner.current.function = Some(name);
+                    assigner.locals.reset();
+                    if function.finalize.is_some() {
+                        assigner.functions.finalize_functions.insert(name);
+                    }
+                    let finalize = function.finalize.map(|finalize| assigner.consume_finalize(name, finalize));
+                    (name, Function { finalize, ..function })
+                })
+                .collect();
+            ProgramScope { functions, ..scope }
+        })
+    }
+
+    /// Resets the collected state, then consumes `program`, consuming the imports of each program before its program scopes.
+    /// The imports are traversed with an explicit stack rather than recursion, so deep import trees cannot overflow the call stack.
+    /// Each import is recorded in the import graph, and each program scope is entered, then consumed with `consume_scope`.
+    pub(crate) fn consume_imports_then_scopes(
+        &mut self,
+        program: Program,
+        mut consume_scope: impl FnMut(&mut Self, ProgramScope) -> ProgramScope,
+    ) -> Program {
+        self.reset();
+        // The root program is named after its program scope.
+        if let Some(name) = program.program_scopes.keys().next() {
+            self.import_graph.add_program(*name);
+            self.current.import_path.push(*name);
+        }
+
+        let mut stack = vec![ImportFrame::new(None, program)];
+        loop {
+            let frame = stack.last_mut().expect("The root program is popped last.");
+            match frame.pending.next() {
+                // Consume the next import of the innermost program before its program scopes.
+                Some((name, (import, span))) => {
+                    self.summary.imports += 1;
+                    // Record the import in the import graph, reporting a program that imports itself.
+                    if let Some(parent) = self.current.import_path.last() {
+                        if *parent == name {
+                            self.emit_err(StaticSingleAssignerError::program_imports_itself(self.resolve(name), span));
+                        }
+                        let is_cycle = self.current.import_path.contains(&name);
+                        self.import_graph.add_import(*parent, name, is_cycle);
+                    }
+                    self.current.import_path.push(name);
+                    stack.push(ImportFrame::new(Some((name, span)), import));
+                }
+                // All imports of the innermost program are consumed, so consume its program scopes.
+                None => {
+                    let frame = stack.pop().expect("The innermost program is on the stack.");
+                    let (functions, temporaries) = (self.summary.functions, self.summary.temporaries);
+                    let records = frame
+                        .program_scopes
+                        .values()
+                        .flat_map(|scope| scope.structs.iter())
+                        .filter(|(_, struct_)| struct_.is_record)
+                        .count();
+                    let program_scopes = frame
+                        .program_scopes
+                        .into_iter()
+                        .map(|(name, scope)| {
+                            self.enter_program_scope(scope.program_id);
+                            (name, consume_scope(self, scope))
+                        })
+                        .collect();
+                    // The programs that are still on the stack import this one, so their number is its import depth.
+                    self.summary.add_import_level(
+                        stack.len(),
+                        self.summary.functions - functions,
+                        self.summary.temporaries - temporaries,
+                        records,
+                    );
+                    let program = Program { imports: frame.imports, program_scopes };
+                    match (frame.import, stack.last_mut()) {
+                        (Some((name, span)), Some(parent)) => {
+                            self.current.import_path.pop();
+                            parent.imports.insert(name, (program, span));
+                        }
+                        _ => {
+                            self.current.import_path.clear();
+                            return program;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Enters the program scope named by `program_id`, setting its record layout and the prefix of the names introduced in it.
+    pub(crate) fn enter_program_scope(&mut self, program_id: ProgramId) {
+        self.current.record_layout = self.options.layout_of(&program_id);
+        self.current.scope_prefix = self.options.prefix_scope_names.then_some(program_id.name.name);
+        self.current.program_id = Some(program_id);
+    }
+
+    /// Returns the name of each field of `record` after `options.field_name_transform`, where the reserved fields keep their names.
+    /// Returns two fields whose transformed names collide, along with the transformed name, if any.
+    pub(crate) fn transform_field_names(
+        &self,
+        record: &Struct,
+    ) -> Result<IndexMap<Symbol, Symbol>, (Symbol, Symbol, Symbol)> {
+        let mut originals: IndexMap<Symbol, Symbol> = IndexMap::new();
+        for member in record.members.iter() {
+            let name = member.name();
+            let transformed = match &self.options.field_name_transform {
+                Some(transform) if !self.current.record_layout.is_reserved(name) => transform.apply(name),
+                _ => name,
+            };
+            // Note that a field declared twice is reported by `consume_struct`, rather than as a collision.
+            match originals.insert(transformed, name) {
+                Some(first) if first != name => return Err((first, name, transformed)),
+                _ => {}
+            }
+        }
+        Ok(originals.into_iter().map(|(transformed, name)| (name, transformed)).collect())
+    }
+
+    /// Returns the name of each member of `struct_` in the source, paired with its name in the output, in the order of the output.
+    /// As in `consume_struct`, the names of the fields of a record are transformed first, and the transformed names are then
+    /// arranged by the record layout, unless `options.preserve_member_order` is set.
+    pub(crate) fn output_member_names(&self, struct_: &Struct) -> Vec<(Symbol, Symbol)> {
+        let transformed = match struct_.is_record {
+            true => self.transform_field_names(struct_).ok(),
+            false => None,
+        };
+        let names = struct_
+            .members
+            .iter()
+            .map(|member| {
+                let name = member.name();
+                (name, transformed.as_ref().and_then(|names| names.get(&name).copied()).unwrap_or(name))
+            })
+            .collect();
+        match struct_.is_record && !self.options.preserve_member_order {
+            true => self.current.record_layout.arrange(names, |(_, name)| *name).0,
+            false => names,
+        }
+    }
+
+    /// Returns the definition of the struct named `struct_name`, rewritten by `options.struct_rewriter` as in `consume_struct`.
+    pub(crate) fn struct_definition(&self, struct_name: Symbol) -> Option<Cow<'a, Struct>> {
+        let definition = self.symbol_table.lookup_struct(struct_name)?;
+        Some(match &self.options.struct_rewriter {
+            Some(rewriter) => Cow::Owned(rewriter.rewrite(definition.clone())),
+            None => Cow::Borrowed(definition),
+        })
+    }
+
+    /// Returns the name of `field` of the struct named `struct_name` after `options.field_name_transform`.
+    /// The fields of structs that are not records, and of records whose transformed names collide, keep their names.
+    pub(crate) fn transformed_field_name(&self, struct_name: Symbol, field: Symbol) -> Symbol {
+        if self.options.field_name_transform.is_none() {
+            return field;
+        }
+        match self.struct_definition(struct_name) {
+            Some(record) if record.is_record => {
+                self.transform_field_names(&record).ok().and_then(|names| names.get(&field).copied()).unwrap_or(field)
+            }
+            _ => field,
+        }
+    }
+
+    /// Consumes `function` if it is selected, i.e. if it is in focus and compiled for an active target, and the deadline has not passed.
+    /// Once the pass has stopped at a record that needed reordering, or the rename table has exceeded its limit, no function is selected.
+    /// A function is in focus if no focus is set or it is the focused function.
+    /// Functions that are not selected are returned unchanged.
+    pub(crate) fn consume_selected_function(&mut self, function: Function) -> Function {
+        let stopped = self.stop.first_reordering.is_some() || self.stop.rename_limit_exceeded.is_some();
+        match self.is_selected(&function) && !stopped && !self.deadline_passed(&function) {
+            true => self.consume_function(function),
+            false => function,
+        }
+    }
+
+    /// Returns `true` if `function` is in focus and compiled for an active target, regardless of whether the pass has stopped.
+    fn is_selected(&self, function: &Function) -> bool {
+        let in_focus = self.options.focus.map_or(true, |focus| focus == function.identifier.name);
+        in_focus && self.options.target_filter.as_ref().map_or(true, |filter| filter.is_active(function))
+    }
+
+    /// Returns `true` if the deadline has passed before `function` is consumed, reporting it the first time.
+    /// Note that the deadline is only checked between functions, so that the check does not slow down the pass.
+    pub(crate) fn deadline_passed(&mut self, function: &Function) -> bool {
+        if let Some(deadline) = self.options.deadline {
+            if !self.stop.timed_out && Instant::now() >= deadline {
+                self.stop.timed_out = true;
+                self.emit_err(StaticSingleAssignerError::deadline_exceeded(
+                    self.resolve(function.identifier.name),
+                    function.span,
+                ));
+            }
+        }
+        self.stop.timed_out
+    }
+
+    /// Consumes the structs and functions of a program scope in the order in which they appear in the source.
+    /// Note that the consumed structs and functions are returned in their original order.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn consume_in_source_order(
+        &mut self,
+        structs: Vec<(Symbol, Struct)>,
+        functions: Vec<(Symbol, Function)>,
+    ) -> (Vec<(Symbol, Struct)>, Vec<(Symbol, Function)>) {
+        /// An item of a program scope, indexed by its position in its collection.
+        enum ScopeItem {
+            Struct(usize),
+            Function(usize),
+        }
+
+        // Sort the items by the start of their spans.
+        let mut items: Vec<(Span, ScopeItem)> = structs
+            .iter()
+            .enumerate()
+            .map(|(index, (_, struct_))| (struct_.span, ScopeItem::Struct(index)))
+            .chain(
+                functions.iter().enumerate().map(|(index, (_, function))| (function.span, ScopeItem::Function(index))),
+            )
+            .collect();
+        items.sort_by_key(|(span, _)| span.lo);
+
+        // Consume each item in place.
+        let mut structs: Vec<Option<(Symbol, Struct)>> = structs.into_iter().map(Some).collect();
+        let mut functions: Vec<Option<(Symbol, Function)>> = functions.into_iter().map(Some).collect();
+        for (_, item) in items {
+            match item {
+                ScopeItem::Struct(index) => {
+                    let (name, struct_) = structs[index].take().expect("Each struct is consumed exactly once.");
+                    structs[index] = Some((name, self.consume_struct(struct_)));
+                }
+                ScopeItem::Function(index) => {
+                    let (name, function) = functions[index].take().expect("Each function is consumed exactly once.");
+                    functions[index] = Some((name, self.consume_selected_function(function)));
+                }
+            }
+        }
+
+        (structs.into_iter().flatten().collect(), functions.into_iter().flatten().collect())
+    }
+
+    /// Resets the state collected while consuming a program, so that the pass can be reused for another program.
+    pub(crate) fn reset(&mut self) {
+        self.current.reset(self.options.record_layout.clone());
+        self.locals.reset();
+        self.naming.reset();
+        self.functions.reset();
+        self.records.reset();
+        self.stop.reset();
+        self.summary = SsaSummary::default();
+        self.import_graph = SsaImportTree::default();
+        self.coverage = NodeCoverage::default();
+    }
+
+    /// Tallies the kinds of `statement` and its expressions, before it is consumed, if `options.record_coverage` is set.
+    pub(crate) fn record_coverage(&mut self, statement: &Statement) {
+        if self.options.record_coverage {
+            CoverageCounter(&mut self.coverage).visit_statement(statement);
+        }
+    }
+
+    /// Records the nesting of the expressions in `statement`, before it is consumed, against the current function.
+    pub(crate) fn record_nesting_depth(&mut self, statement: &Statement) {
+        let mut nesting = NestingDepth::default();
+        nesting.visit_statement(statement);
+        if let Some(function) = self.current.function {
+            let depth = self.functions.nesting_depths.entry(function).or_default();
+            *depth = (*depth).max(nesting.max);
+        }
+        self.summary.peak_expression_depth = self.summary.peak_expression_depth.max(nesting.max);
+    }
+
+    /// Records the `expansion` of the block of `function`, warning if it exceeds `options.expansion_warning_threshold`.
+    pub(crate) fn record_expansion(&mut self, function: Symbol, expansion: StatementExpansion, span: Span) {
+        if let Some(threshold) = self.options.expansion_warning_threshold {
+            if expansion.ratio() > threshold {
+                self.emit_warning(StaticSingleAssignerWarning::statement_expansion_exceeded(
+                    self.resolve(function),
+                    expansion.input,
+                    expansion.output,
+                    threshold,
+                    span,
+                ));
+            }
+        }
+        self.functions.expansions.insert(function, expansion);
+    }
+
+    /// Retains the final `table` of the function or finalize block of `function`, if `options.retain_rename_tables` is set.
+    pub(crate) fn retain_rename_table(&mut self, function: Symbol, table: S, is_finalize: bool) {
+        if self.options.retain_rename_tables {
+            // The scope beneath the table is the empty scope of the program scope.
+            let table = table.into_innermost();
+            match is_finalize {
+                false => self.naming.rename_tables.insert(function, table),
+                true => self.naming.finalize_rename_tables.insert(function, table),
+            };
+        }
+    }
+
+    /// Returns the string of `symbol` for a diagnostic, as rendered by `options.symbol_resolver` if it is set.
+    pub(crate) fn resolve(&self, symbol: Symbol) -> String {
+        match &self.options.symbol_resolver {
+            Some(resolver) => resolver.resolve(symbol),
+            None => symbol.to_string(),
+        }
+    }
+
+    /// Emits a Static Single Assigner Error.
+    /// Note that emitting an error does not stop the traversal, so that all errors in the program are reported.
+    pub(crate) fn emit_err(&self, err: StaticSingleAssignerError) {
+        self.handler.emit_err(err);
+    }
+
+    /// Emits a Static Single Assigner Warning.
+    pub(crate) fn emit_warning(&self, warning: StaticSingleAssignerWarning) {
+        self.handler.emit_warning(warning.into());
+    }
+
+    /// Emits an error if `identifier` is being reassigned and is a constant input, if `options.check_constant_input_reassignment` is set.
+    pub(crate) fn check_constant_input_reassignment(&self, identifier: &Identifier) {
+        if self.options.check_constant_input_reassignment && self.locals.constant_inputs.contains(&identifier.name) {
+            self.emit_err(StaticSingleAssignerError::constant_input_reassigned(
+                self.resolve(identifier.name),
+                identifier.span,
+            ));
+        }
+    }
+
+    /// Emits a warning if `identifier` is being defined in a function body and shadows one of the function's inputs.
+    pub(crate) fn check_shadowed_input(&self, identifier: &Identifier) {
+        if self.options.warn_shadowed_inputs {
+            if let Some(input) = self.shadowed_input(identifier) {
+                self.emit_warning(
+                    StaticSingleAssignerWarning::input_shadowed_by_definition(
+                        self.resolve(identifier.name),
+                        identifier.span,
+                    )
+                    .with_label(input.span, "the input is declared here"),
+                );
+            }
+        }
+    }
+
+    /// Emits an error if `identifier` is being defined in a function body and reuses the name of a function input,
+    /// if `options.pin_input_names` is set.
+    pub(crate) fn check_pinned_input(&self, identifier: &Identifier) {
+        if self.options.pin_input_names {
+            if let Some(input) = self.shadowed_input(identifier) {
+                self.emit_err(
+                    StaticSingleAssignerError::pinned_input_redefined(self.resolve(identifier.name), identifier.span)
+                        .with_label(input.span, "the input is declared here"),
+                );
+            }
+        }
+    }
+
+    /// Returns the input that the name of `identifier` resolves to in the enclosing scopes, if any.
+    /// The inputs are seeded into the outermost scope of the function, and no new name reuses the name of an input,
+    /// so the name of an input resolves to the node ID of the input from any nested scope.
+    fn shadowed_input(&self, identifier: &Identifier) -> Option<&Identifier> {
+        let id = self.rename_table.lookup_id(&identifier.name)?;
+        self.locals.input_identifiers.get(&identifier.name).filter(|input| input.id == *id)
     }
 
     /// Pushes a new scope, setting the current scope as the new scope's parent.
     pub(crate) fn push(&mut self) {
-        let parent_table = core::mem::take(&mut self.rename_table);
-        self.rename_table = RenameTable::new(Some(Box::from(parent_table)));
+        self.rename_table.push();
+        self.locals.scope_depth += 1;
+        self.summary.peak_scope_depth = self.summary.peak_scope_depth.max(self.locals.scope_depth);
+
+        // Number the new block among its siblings.
+        let siblings = match self.locals.blocks.last_mut() {
+            Some(parent) => &mut parent.children,
+            None => &mut self.locals.root_blocks,
+        };
+        let position = *siblings;
+        *siblings += 1;
+        self.locals.blocks.push(BlockFrame { position, ..Default::default() });
+    }
+
+    /// Pops the current scope, returning the scope stack as it was before popping.
+    pub(crate) fn pop(&mut self) -> S {
+        self.locals.scope_depth = self.locals.scope_depth.saturating_sub(1);
+        if let Some(block) = self.locals.blocks.pop() {
+            self.stop.rename_entries = self.stop.rename_entries.saturating_sub(block.entries);
+        }
+        self.rename_table.pop()
+    }
+
+    /// Maps `symbol` to `new_symbol` in the current scope of the rename table, counting the entry against `options.max_rename_entries`.
+    /// Each update counts as one entry, so the count bounds the number of mappings stored by the scopes on the stack.
+    /// The first function exceeding the limit is recorded, to be reported once it is consumed.
+    pub(crate) fn update_rename_table(&mut self, symbol: Symbol, new_symbol: Symbol, id: NodeID) {
+        self.rename_table.update(symbol, new_symbol, id);
+        self.stop.rename_entries += 1;
+        if let Some(block) = self.locals.blocks.last_mut() {
+            block.entries += 1;
+        }
+        if let Some(max) = self.options.max_rename_entries {
+            if self.stop.rename_entries > max && self.stop.rename_limit_exceeded.is_none() {
+                self.stop.rename_limit_exceeded = self.current.function;
+            }
+        }
+    }
+
+    /// Returns a new unique `Symbol`, applying the rename transformation if one is configured.
+    /// If `options.prefix_scope_names` is set, the name is prefixed with the name of the current program scope.
+    /// If `options.valid_identifiers` is set, the name is mangled into a valid Leo identifier, e.g. `a__3` or `var__4`,
+    /// and a collision with a name in the source is reported.
+    pub(crate) fn unique_symbol(&mut self, arg: impl Display, separator: impl Display) -> Symbol {
+        let (arg, separator) = match self.options.valid_identifiers {
+            true => (arg.to_string().trim_start_matches('$').to_string(), "__".to_string()),
+            false => (arg.to_string(), separator.to_string()),
+        };
+        let symbol = match (self.options.numbering, self.locals.blocks.last_mut()) {
+            (NumberingScheme::PerBlock, Some(block)) => {
+                let index = block.names;
+                block.names += 1;
+                let path =
+                    self.locals.blocks.iter().map(|block| block.position.to_string()).collect::<Vec<_>>().join("_");
+                Symbol::intern(&format!("{arg}{separator}{path}{separator}{index}"))
+            }
+            // Names introduced outside of a block are numbered per function.
+            _ => self.assigner.unique_symbol(arg, &separator),
+        };
+        let symbol = match self.current.scope_prefix {
+            Some(prefix) => {
+                Symbol::intern(&format!("{prefix}{separator}{}", symbol.to_string().trim_start_matches('$')))
+            }
+            None => symbol,
+        };
+        let symbol = match &self.options.rename_transform {
+            Some(transform) => transform.apply(symbol),
+            None => symbol,
+        };
+        if let Some(span) = self.locals.source_names.get(&symbol) {
+            self.emit_err(StaticSingleAssignerError::introduced_name_collides(self.resolve(symbol), *span));
+        }
+        symbol
     }
 
-    /// If the RenameTable has a parent, then `self.rename_table` is set to the parent, otherwise it is set to a default `RenameTable`.
-    pub(crate) fn pop(&mut self) -> RenameTable {
-        let parent = self.rename_table.parent.clone().unwrap_or_default();
-        core::mem::replace(&mut self.rename_table, *parent)
+    /// Returns a new name for the variable `symbol`, as decided by `options.rename_hook` if it is set.
+    /// If `options.collision_checker` is set, a rejected name is replaced by the next name proposed by the pass, bypassing the hook.
+    /// If `CollisionChecker::MAX_ATTEMPTS` names are rejected, this is reported at `span` and the last name is used.
+    pub(crate) fn new_name(&mut self, symbol: Symbol, span: Span) -> Symbol {
+        let mut name = self.decided_name(symbol, span);
+        let Some(checker) = self.options.collision_checker.clone() else {
+            return name;
+        };
+        for _ in 1..CollisionChecker::MAX_ATTEMPTS {
+            if checker.accepts(name, &self.variables_in_scope()) {
+                return name;
+            }
+            name = self.unique_symbol(self.name_stem(symbol), "$");
+        }
+        if !checker.accepts(name, &self.variables_in_scope()) {
+            self.emit_err(StaticSingleAssignerError::new_names_rejected(
+                self.resolve(symbol),
+                CollisionChecker::MAX_ATTEMPTS,
+                span,
+            ));
+        }
+        name
+    }
+
+    /// Returns the stem of the new names of the variable `symbol`, which is `symbol` itself unless the numbering is `NumberingScheme::Canonical`.
+    fn name_stem(&self, symbol: Symbol) -> Symbol {
+        match self.options.numbering {
+            NumberingScheme::Canonical => Symbol::intern("$v"),
+            NumberingScheme::PerFunction | NumberingScheme::PerBlock => symbol,
+        }
+    }
+
+    /// Returns the variables in the current scope, mapped to their current names.
+    fn variables_in_scope(&self) -> IndexMap<Symbol, Symbol> {
+        let variables: IndexSet<Symbol> =
+            self.locals.input_identifiers.keys().chain(self.naming.origins.values()).copied().collect();
+        variables
+            .into_iter()
+            .filter_map(|variable| self.rename_table.lookup(variable).map(|name| (variable, *name)))
+            .collect()
+    }
+
+    /// Returns a new name for the variable `symbol`, as decided by `options.rename_hook` if it is set.
+    /// A name chosen by the hook must not be in use in the function, e.g. by an input or an earlier choice; otherwise it is reported at `span`.
+    fn decided_name(&mut self, symbol: Symbol, span: Span) -> Symbol {
+        let proposed = self.unique_symbol(self.name_stem(symbol), "$");
+        let chosen = match &self.options.rename_hook {
+            Some(hook) => hook.decide(symbol, proposed),
+            None => return proposed,
+        };
+        if chosen == proposed {
+            return proposed;
+        }
+        match self.locals.input_identifiers.contains_key(&chosen) || !self.locals.chosen_names.insert(chosen) {
+            true => {
+                self.emit_err(StaticSingleAssignerError::chosen_name_not_unique(
+                    self.resolve(chosen),
+                    self.resolve(symbol),
+                    span,
+                ));
+                proposed
+            }
+            false => chosen,
+        }
+    }
+
+    /// Maps `symbol` to `new_symbol` in the current `RenameTable`, recording the original name of `new_symbol` and the renaming in the history.
+    /// The renaming is also recorded in the timeline if `options.record_timeline` is set.
+    /// Each renaming is logged as a `tracing` event at trace level with the target `leo_passes::ssa`, which costs nothing unless the level is enabled.
+    pub(crate) fn rename(&mut self, symbol: Symbol, new_symbol: Symbol, id: NodeID) {
+        tracing::trace!(
+            target: "leo_passes::ssa",
+            function = %self.current.function.map(|function| function.to_string()).unwrap_or_default(),
+            original = %symbol,
+            renamed = %new_symbol,
+            "renamed variable"
+        );
+        self.update_rename_table(symbol, new_symbol, id);
+        self.naming.origins.insert(new_symbol, symbol);
+        if let Some(function) = self.current.function {
+            *self.naming.renaming_counts.entry(function).or_default() += 1;
+        }
+        if let (Some(program), Some(function)) = (self.current.program_id, self.current.function) {
+            self.naming.history.push(RenameRecord {
+                program: program.into(),
+                function,
+                original: symbol,
+                renamed: new_symbol,
+                scope_depth: self.locals.scope_depth,
+            });
+        }
+        if self.options.record_timeline {
+            self.locals.renamings.push((symbol, new_symbol));
+        }
     }
 
     pub(crate) fn simple_assign_statement(&mut self, identifier: Identifier, rhs: Expression) -> Statement {
         // Update the type table.
-        let type_ = match self.type_table.get(&rhs.id()) {
-            Some(type_) => type_,
-            None => unreachable!("Type checking guarantees that all expressions have a type."),
-        };
-        self.type_table.insert(identifier.id(), type_);
+        // Note that type checking gives every expression a type, so a missing type is only reported if the input is malformed.
+        // Since the expression then usually contains an error already reported by the pass, e.g. a use before definition, it is not reported again.
+        match self.type_table.get(&rhs.id()) {
+            Some(type_) => self.type_table.insert(identifier.id(), type_),
+            None if self.handler.had_errors() => {}
+            None => self.emit_err(StaticSingleAssignerError::untyped_expression(rhs.span())),
+        }
         // Update the rename table.
-        self.rename_table.update(identifier.name, identifier.name, identifier.id);
+        self.update_rename_table(identifier.name, identifier.name, identifier.id);
         // Construct the statement.
         self.assigner.simple_assign_statement(identifier, rhs, self.node_builder.next_id())
     }
@@ -74,7 +1238,21 @@ impl<'a> StaticSingleAssigner<'a> {
     /// The lhs is guaranteed to be unique with respect to the `Assigner`.
     pub(crate) fn unique_simple_assign_statement(&mut self, expr: Expression) -> (Identifier, Statement) {
         // Create a new variable for the expression.
-        let name = self.assigner.unique_symbol("$var", "$");
+        let name = self.unique_symbol("$var", "$");
+        self.summary.temporaries += 1;
+        self.locals.function_temporaries += 1;
+
+        // Check that the function has not exceeded its budget of temporaries.
+        // Note that the error is only emitted once per function, by the temporary that first exceeds the budget.
+        if let (Some(budget), Some(function)) = (self.options.max_temporaries, self.current.function) {
+            if self.locals.function_temporaries == budget.saturating_add(1) {
+                self.emit_err(StaticSingleAssignerError::temporaries_budget_exceeded(
+                    self.resolve(function),
+                    budget,
+                    expr.span(),
+                ));
+            }
+        }
 
         // Create a new identifier for the variable.
         let place = Identifier { name, span: Default::default(), id: self.node_builder.next_id() };
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    DefinitionStatement,
+    ExpressionVisitor,
+    Function,
+    Input,
+    Mapping,
+    Output,
+    ProgramVisitor,
+    StatementVisitor,
+    Struct,
+    StructExpression,
+    Type,
+};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// Collects the names of the structs referenced in a program scope, by the types of the members of structs, mappings,
+/// and the inputs and outputs of functions, and by the types and struct expressions in the bodies of functions.
+/// Note that the inputs and outputs of external records are not collected, since they refer to the structs of another program.
+#[derive(Default)]
+pub(crate) struct StructReferences {
+    /// The names of the referenced structs.
+    pub(crate) names: IndexSet<Symbol>,
+}
+
+impl StructReferences {
+    /// Records each struct named in `type_`.
+    fn reference(&mut self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => {
+                self.names.insert(identifier.name);
+            }
+            Type::Array(array) => self.reference(array.element_type()),
+            Type::Tuple(tuple) => tuple.elements().iter().for_each(|element| self.reference(element)),
+            Type::Mapping(mapping) => {
+                self.reference(&mapping.key);
+                self.reference(&mapping.value);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for StructReferences {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.names.insert(input.name.name);
+        for expression in input.members.iter().filter_map(|member| member.expression.as_ref()) {
+            self.visit_expression(expression, additional);
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for StructReferences {
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.reference(&input.type_);
+        self.visit_expression(&input.value, &());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for StructReferences {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.members.iter().for_each(|member| self.reference(&member.type_));
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.reference(&input.key_type);
+        self.reference(&input.value_type);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        let inputs = input.input.iter().chain(input.finalize.iter().flat_map(|finalize| finalize.input.iter()));
+        for input in inputs {
+            if let Input::Internal(input) = input {
+                self.reference(&input.type_);
+            }
+        }
+        let outputs = input.output.iter().chain(input.finalize.iter().flat_map(|finalize| finalize.output.iter()));
+        for output in outputs {
+            if let Output::Internal(output) = output {
+                self.reference(&output.type_);
+            }
+        }
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+    }
+}
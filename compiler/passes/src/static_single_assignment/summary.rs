@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A summary of the transformations applied by the static single assignment pass.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SsaSummary {
+    /// The number of functions consumed.
+    pub functions: usize,
+    /// The number of records whose members were reordered.
+    pub records_reordered: usize,
+    /// The number of temporaries introduced, e.g. `$var$0`.
+    pub temporaries: usize,
+    /// The number of imported programs consumed.
+    pub imports: usize,
+    /// The maximum depth of the scope stack, where the body of a function is at depth one.
+    pub peak_scope_depth: usize,
+    /// The deepest nesting of expressions in the source of the consumed functions, e.g. two for `a + (b * c)`.
+    pub peak_expression_depth: usize,
+    /// The metrics of the programs consumed at each import depth, where the root program is at depth zero and its direct imports at depth one.
+    /// A program imported at several depths is counted at each of them.
+    pub import_levels: Vec<ImportLevelSummary>,
+}
+
+/// The metrics of the programs consumed at one import depth.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImportLevelSummary {
+    /// The number of programs consumed.
+    pub programs: usize,
+    /// The number of functions consumed.
+    pub functions: usize,
+    /// The number of temporaries introduced.
+    pub temporaries: usize,
+    /// The number of records consumed.
+    pub records: usize,
+}
+
+impl SsaSummary {
+    /// Adds the metrics of a program consumed at import depth `depth` to its level.
+    pub(crate) fn add_import_level(&mut self, depth: usize, functions: usize, temporaries: usize, records: usize) {
+        if self.import_levels.len() <= depth {
+            self.import_levels.resize(depth + 1, ImportLevelSummary::default());
+        }
+        let level = &mut self.import_levels[depth];
+        level.programs += 1;
+        level.functions += functions;
+        level.temporaries += temporaries;
+        level.records += records;
+    }
+
+    /// Returns the change from `self` to `other`, e.g. from the summary of a program before a source change to the summary after it.
+    pub fn diff(&self, other: &SsaSummary) -> SsaSummaryDiff {
+        let delta = |before: usize, after: usize| after as isize - before as isize;
+        SsaSummaryDiff {
+            functions: delta(self.functions, other.functions),
+            records_reordered: delta(self.records_reordered, other.records_reordered),
+            temporaries: delta(self.temporaries, other.temporaries),
+            imports: delta(self.imports, other.imports),
+            peak_scope_depth: delta(self.peak_scope_depth, other.peak_scope_depth),
+            peak_expression_depth: delta(self.peak_expression_depth, other.peak_expression_depth),
+        }
+    }
+}
+
+/// The change between two `SsaSummary`s, where a positive delta is an increase.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SsaSummaryDiff {
+    /// The change in the number of functions consumed.
+    pub functions: isize,
+    /// The change in the number of records whose members were reordered.
+    pub records_reordered: isize,
+    /// The change in the number of temporaries introduced.
+    pub temporaries: isize,
+    /// The change in the number of imported programs consumed.
+    pub imports: isize,
+    /// The change in the maximum depth of the scope stack.
+    pub peak_scope_depth: isize,
+    /// The change in the deepest nesting of expressions.
+    pub peak_expression_depth: isize,
+}
+
+impl SsaSummaryDiff {
+    /// Returns `true` if the summaries are identical.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{program_from_binary, program_to_binary, read_program_binary, write_program_binary, SsaOptions};
+
+use leo_ast::{Identifier, Program, ProgramConsumer};
+use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8, b: u8, c: bool) -> u8 {
+        let d: u8 = a + b;
+        if c {
+            d = d * 2u8;
+        }
+        return d;
+    }
+}";
+
+/// Returns `program` with the names of its program scopes and those of its imports as they are deserialized,
+/// i.e. without their spans and node IDs.
+fn with_plain_program_names(mut program: Program) -> Program {
+    program.imports.values_mut().for_each(|(import, _)| *import = with_plain_program_names(core::mem::take(import)));
+    for scope in program.program_scopes.values_mut() {
+        scope.program_id.name = Identifier::new(scope.program_id.name.name, Default::default());
+    }
+    program
+}
+
+#[test]
+fn test_binary_round_trip_preserves_the_consumed_program() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        let bytes = program_to_binary(&program).unwrap();
+        let reloaded = program_from_binary(&bytes).unwrap();
+        assert_eq!(reloaded, with_plain_program_names(program.clone()));
+        assert_eq!(reloaded.to_string(), program.to_string());
+    })
+}
+
+#[test]
+fn test_binary_round_trip_through_a_file() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        let path = std::env::temp_dir().join(format!("leo-ssa-binary-{}.bin", std::process::id()));
+        write_program_binary(&program, &path).unwrap();
+        let reloaded = read_program_binary(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(reloaded.unwrap(), with_plain_program_names(program));
+    })
+}
+
+#[test]
+fn test_binary_round_trip_preserves_imports() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        // `test` imports a copy of its own program scope, named `other`.
+        let mut other = frontend.program.clone();
+        other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+        let mut program = frontend.program.clone();
+        program.imports = [(Symbol::intern("other"), (other, Span::default()))].into_iter().collect();
+
+        let reloaded = program_from_binary(&program_to_binary(&program).unwrap()).unwrap();
+        assert_eq!(reloaded, with_plain_program_names(program.clone()));
+    })
+}
+
+#[test]
+fn test_truncated_binary_is_rejected() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let bytes = program_to_binary(&frontend.program).unwrap();
+        assert!(program_from_binary(&bytes[..bytes.len() / 2]).is_err());
+    })
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, member_names, Frontend};
+use crate::{
+    NumberingScheme,
+    RecordLayout,
+    RenameTransform,
+    SsaObserver,
+    SsaOptions,
+    StaticSingleAssigner,
+    StaticSingleAssignerBuilder,
+};
+
+use leo_ast::{Program, ProgramConsumer};
+use leo_span::{sym, symbol::create_session_if_not_set_then, Symbol};
+
+use std::{cell::RefCell, rc::Rc};
+
+const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        gates: u64,
+        owner: address,
+    }
+
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a + b;
+        if c == 0u8 {
+            c = 1u8;
+        }
+        return c;
+    }
+}";
+
+/// An `SsaObserver` that records the names of the functions entered.
+#[derive(Clone, Default)]
+struct EnteredFunctions(Rc<RefCell<Vec<Symbol>>>);
+
+impl SsaObserver for EnteredFunctions {
+    fn enter_function(&mut self, function: Symbol) {
+        self.0.borrow_mut().push(function);
+    }
+}
+
+/// Returns a `StaticSingleAssignerBuilder` over the state of `frontend`.
+fn builder(frontend: &Frontend) -> StaticSingleAssignerBuilder<'_> {
+    StaticSingleAssignerBuilder::new(
+        &frontend.node_builder,
+        &frontend.symbol_table,
+        &frontend.type_table,
+        &frontend.assigner,
+        &frontend.handler,
+    )
+}
+
+/// Consumes `PROGRAM` with the assigner returned by `build`.
+fn consume(build: impl FnOnce(&Frontend) -> StaticSingleAssigner<'_>) -> (Program, Frontend) {
+    let frontend = Frontend::new(PROGRAM);
+    let program = build(&frontend).consume_program(frontend.program.clone());
+    (program, frontend)
+}
+
+#[test]
+fn test_builder_defaults_match_constructor() {
+    create_session_if_not_set_then(|_| {
+        let (built, _) = consume(|frontend| builder(frontend).build());
+        let (constructed, _) = consume(|frontend| frontend.assigner(SsaOptions::default()));
+
+        assert_eq!(built.to_string(), constructed.to_string());
+    })
+}
+
+#[test]
+fn test_builder_sets_several_options() {
+    create_session_if_not_set_then(|_| {
+        let observer = EnteredFunctions::default();
+        let (program, frontend) = consume(|frontend| {
+            builder(frontend)
+                .with_record_layout(RecordLayout::new(vec![sym::owner, sym::gates]))
+                .with_strict_records(true)
+                .with_numbering(NumberingScheme::PerBlock)
+                .with_rename_transform(RenameTransform::new(|symbol| Symbol::intern(&format!("t_{symbol}"))))
+                .with_observer(observer.clone())
+                .build()
+        });
+
+        // The options are equivalent to constructing the assigner with them directly.
+        let options = SsaOptions {
+            record_layout: RecordLayout::new(vec![sym::owner, sym::gates]),
+            strict_records: true,
+            numbering: NumberingScheme::PerBlock,
+            rename_transform: Some(RenameTransform::new(|symbol| Symbol::intern(&format!("t_{symbol}")))),
+            ..Default::default()
+        };
+        let (expected, _) = consume(|frontend| frontend.assigner(options));
+        assert_eq!(program.to_string(), expected.to_string());
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "gates", "amount"]);
+        assert!(function(&program, "main").block.to_string().contains("t_c$"));
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("not in canonical order"), "{}", errors[0]);
+        assert_eq!(*observer.0.borrow(), [Symbol::intern("main")]);
+    })
+}
+
+#[test]
+fn test_builder_options_can_be_overridden() {
+    create_session_if_not_set_then(|_| {
+        let (program, frontend) = consume(|frontend| {
+            builder(frontend)
+                .with_options(SsaOptions { strict_records: true, ..Default::default() })
+                .with_strict_records(false)
+                .with_max_temporaries(1)
+                .build()
+        });
+
+        // Only the budget of temporaries is exceeded, since strict records was overridden.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "gates"]);
+    })
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::SsaOptions;
+
+use leo_ast::{Ast, ProgramConsumer};
+use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+use indexmap::IndexMap;
+
+const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8, b: u8, c: bool) -> u8 {
+        let d: u8 = a + b;
+        if c {
+            d = d * 2u8;
+        }
+        return d;
+    }
+}";
+
+#[test]
+fn test_reloaded_program_produces_identical_output() {
+    create_session_if_not_set_then(|_| {
+        // Consume the freshly parsed program.
+        let fresh = Frontend::new(PROGRAM);
+        let expected = fresh.assigner(SsaOptions::default()).consume_program(fresh.program.clone());
+
+        // Serialize the program as it would be cached, and reload it.
+        let cached = Frontend::new(PROGRAM);
+        let json = Ast::new(cached.program.clone()).to_json_string().expect("The program should serialize.");
+        let reloaded = Ast::from_json_string(&json).expect("The program should deserialize.").into_repr();
+        // Note that the span of the program name is not serialized, so the programs are only compared as source.
+        assert_eq!(reloaded.to_string(), cached.program.to_string());
+
+        // Consuming the reloaded program produces the same output.
+        // Note that the spans of the two programs differ, since each is parsed from its own source file.
+        let output = cached.assigner(SsaOptions::default()).consume_program(reloaded);
+        assert_eq!(output.to_string(), expected.to_string());
+    })
+}
+
+#[test]
+fn test_function_checksums_key_identical_functions_identically() {
+    create_session_if_not_set_then(|_| {
+        let source = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b;
+    }
+
+    function helper(a: u8) -> u8 {
+        let b: u8 = a * 2u8;
+        return b;
+    }
+}";
+        // Returns the checksums of the functions of `source`, which imports a copy of itself named `other`.
+        let run = |source: &str| {
+            let frontend = Frontend::new(source);
+            let mut other = frontend.program.clone();
+            other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+            let mut program = frontend.program.clone();
+            program.imports = [(Symbol::intern("other"), (other, Span::default()))].into_iter().collect();
+
+            let (_, checksums) =
+                frontend.assigner(SsaOptions::default()).consume_program_with_function_checksums(program);
+            checksums.into_iter().map(|(name, checksums)| (name.to_string(), checksums)).collect::<IndexMap<_, _>>()
+        };
+
+        let first = run(source);
+        assert_eq!(first.keys().collect::<Vec<_>>(), [
+            "test.aleo::main",
+            "test.aleo::helper",
+            "other.aleo::main",
+            "other.aleo::helper"
+        ]);
+        // Identical functions have identical checksums in source form, whichever program declares them.
+        assert_eq!(first["test.aleo::main"].before, first["other.aleo::main"].before);
+        assert_ne!(first["test.aleo::main"].before, first["test.aleo::helper"].before);
+
+        // A change to `helper` changes only its checksums.
+        let second = run(&source.replace("2u8", "3u8"));
+        assert_eq!(first["test.aleo::main"], second["test.aleo::main"]);
+        assert_ne!(first["test.aleo::helper"].before, second["test.aleo::helper"].before);
+        assert_ne!(first["test.aleo::helper"].after, second["test.aleo::helper"].after);
+    })
+}
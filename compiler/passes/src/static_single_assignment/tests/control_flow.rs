@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use super::Frontend;
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, d: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = a;
+        } else {
+            b = d;
+        }
+        return b;
+    }
+}";
+
+const EXPECTED: &str = "program test.aleo:
+  transition main:
+    block at depth 1: b -> b$0, b -> b$3
+      b$0 = a;
+      if c
+        then block at depth 2: b -> b$1
+          b$1 = a;
+        else block at depth 2: b -> b$2
+          b$2 = d;
+      $var$4 = (c ? b$1 : b$2);
+      b$3 = $var$4;
+      return b$3
+";
+
+#[test]
+fn test_control_flow_dump_snapshot() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        assert_eq!(assigner.control_flow_dump(&program), EXPECTED);
+    })
+}
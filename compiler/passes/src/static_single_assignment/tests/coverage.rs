@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{NodeCoverage, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+#[test]
+fn test_coverage_tallies_the_consumed_node_kinds() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    transition main(a: u8, b: bool) -> u8 {
+        let p: Point = Point { x: a, y: 1u8 };
+        let c: u8 = b ? p.x : a;
+        if b {
+            c = c + 1u8;
+        } else if c == 0u8 {
+            c = 2u8;
+        }
+        assert(b);
+        return c;
+    }
+}",
+        );
+        let options = SsaOptions { record_coverage: true, ..Default::default() };
+        let mut assigner = frontend.assigner(options);
+        assigner.consume_program(frontend.program.clone());
+
+        // The conditional in the otherwise-block is counted, although it is consumed without a block.
+        let coverage = assigner.coverage();
+        let statements: Vec<(&str, usize)> = coverage.statements.iter().map(|(kind, count)| (*kind, *count)).collect();
+        assert_eq!(statements, [("Definition", 2), ("Conditional", 2), ("Assign", 2), ("Assert", 1), ("Return", 1)]);
+        assert_eq!(coverage.uncovered_statements(), ["Block", "Console", "Const", "Expression", "Iteration"]);
+
+        assert_eq!(coverage.expressions["Struct"], 1);
+        assert_eq!(coverage.expressions["Ternary"], 1);
+        assert_eq!(coverage.expressions["Access"], 1);
+        assert_eq!(coverage.expressions["Binary"], 2);
+        assert_eq!(coverage.expressions["Literal"], 4);
+        assert_eq!(coverage.uncovered_expressions(), ["Array", "Call", "Cast", "Tuple", "Unary", "Unit"]);
+    })
+}
+
+#[test]
+fn test_coverage_is_not_recorded_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        assert_eq!(assigner.coverage(), &NodeCoverage::default());
+    })
+}
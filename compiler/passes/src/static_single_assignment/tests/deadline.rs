@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, Frontend};
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::time::{Duration, Instant};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b;
+    }
+
+    function helper(a: u8) -> u8 {
+        let b: u8 = a * 2u8;
+        return b;
+    }
+}";
+
+#[test]
+fn test_passed_deadline_leaves_functions_unchanged() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        // The deadline passes before the first function is consumed.
+        let options = SsaOptions { deadline: Some(Instant::now()), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Note that functions are compared by their blocks, since `Function` only compares identifiers.
+        for name in ["main", "helper"] {
+            assert_eq!(function(&program, name).block.to_string(), function(&frontend.program, name).block.to_string());
+        }
+
+        // The timeout is reported once.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("exceeded its deadline before consuming the function `main`"));
+    })
+}
+
+#[test]
+fn test_distant_deadline_does_not_interrupt_the_pass() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions { deadline: Some(Instant::now() + Duration::from_secs(3600)), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        assert!(function(&program, "main").block.to_string().contains("b$"));
+        assert!(function(&program, "helper").block.to_string().contains("b$"));
+        assert!(frontend.errors().is_empty());
+    })
+}
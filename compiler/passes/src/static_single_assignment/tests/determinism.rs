@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{checksum, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8, b: u8, c: bool) -> u8 {
+        let d: u8 = a * b + 1u8;
+        if c {
+            d = d - a;
+            if d == 0u8 {
+                d = b;
+            }
+        } else {
+            d = b;
+        }
+        return d;
+    }
+
+    function helper(a: u8) -> (u8, u8) {
+        return (a + 1u8, a * 2u8);
+    }
+}";
+
+#[test]
+fn test_output_is_identical_across_runs() {
+    create_session_if_not_set_then(|_| {
+        let run = || {
+            let frontend = Frontend::new(PROGRAM);
+            let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+            program.to_string()
+        };
+
+        let first = run();
+        // The output includes the temporaries introduced by the pass.
+        assert!(first.contains("$var$"), "{first}");
+        assert_eq!(first, run());
+    })
+}
+
+#[test]
+fn test_checksum_is_identical_across_runs() {
+    create_session_if_not_set_then(|_| {
+        let run = |source: &str| {
+            let frontend = Frontend::new(source);
+            let (program, hash) =
+                frontend.assigner(SsaOptions::default()).consume_program_with_checksum(frontend.program.clone());
+            assert_eq!(hash, checksum(&program));
+            hash
+        };
+
+        // The second run loads the source at a different position in the source map, so its spans differ.
+        let first = run(PROGRAM);
+        assert_eq!(first.len(), 64);
+        assert_eq!(first, run(PROGRAM));
+        // A change to the program changes its checksum.
+        assert_ne!(first, run(&PROGRAM.replace("1u8", "3u8")));
+    })
+}
+
+#[test]
+fn test_concurrent_output_is_identical_to_sequential_output() {
+    // Returns the SSA form of `source`, consumed in a session of the current thread.
+    let run = |source: String| create_session_if_not_set_then(|_| Frontend::new(&source).output(SsaOptions::default()));
+    let sources: Vec<String> = (1..=4)
+        .map(|i| PROGRAM.replace("test.aleo", &format!("test{i}.aleo")).replace("1u8", &format!("{i}u8")))
+        .collect();
+
+    let sequential: Vec<String> = sources.iter().cloned().map(run).collect();
+    // Each thread builds its own session and assigner.
+    let concurrent: Vec<String> = std::thread::scope(|scope| {
+        let handles: Vec<_> = sources.iter().cloned().map(|source| scope.spawn(move || run(source))).collect();
+        handles.into_iter().map(|handle| handle.join().expect("A thread should not panic.")).collect()
+    });
+    assert_eq!(concurrent, sequential);
+}
+
+const INTERLEAVED: &str = "
+program test.aleo {
+    record First {
+        owner: address,
+        owners: u8,
+    }
+
+    transition main(a: u8) -> u8 {
+        let a: u8 = 1u8;
+        return a;
+    }
+
+    record Second {
+        owner: address,
+        owner_id: u8,
+    }
+
+    function helper(b: u8) -> u8 {
+        let b: u8 = 2u8;
+        return b;
+    }
+}";
+
+#[test]
+fn test_source_order_interleaves_diagnostics() {
+    create_session_if_not_set_then(|_| {
+        // Returns the names in the warnings emitted when consuming `INTERLEAVED` with `source_order`, along with the output.
+        let consume = |source_order| {
+            let frontend = Frontend::new(INTERLEAVED);
+            let options = SsaOptions {
+                source_order,
+                warn_near_reserved_fields: true,
+                warn_shadowed_inputs: true,
+                ..Default::default()
+            };
+            let program = frontend.assigner(options).consume_program(frontend.program.clone());
+            let names: Vec<String> = frontend
+                .warnings()
+                .iter()
+                .map(|warning| {
+                    let warning = warning.to_string();
+                    ["owners", "owner_id", "a", "b"]
+                        .into_iter()
+                        .find(|name| warning.contains(&format!("`{name}`")))
+                        .unwrap_or_else(|| panic!("Unexpected warning: {warning}"))
+                        .to_string()
+                })
+                .collect();
+            (names, program)
+        };
+
+        // By default, the structs are consumed before the functions.
+        let (names, _) = consume(false);
+        assert_eq!(names, ["owners", "owner_id", "a", "b"]);
+
+        // In source order, the diagnostics follow the source, while the structs and functions keep their collections and order.
+        let (names, program) = consume(true);
+        assert_eq!(names, ["owners", "a", "owner_id", "b"]);
+        let scope = program.program_scopes.values().next().unwrap();
+        let structs: Vec<String> = scope.structs.iter().map(|(name, _)| name.to_string()).collect();
+        let functions: Vec<String> = scope.functions.iter().map(|(name, _)| name.to_string()).collect();
+        assert_eq!(structs, ["First", "Second"]);
+        assert_eq!(functions, ["main", "helper"]);
+    })
+}
+
+#[test]
+fn test_options_can_be_shared_between_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SsaOptions>();
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{SsaOptions, StatementExpansion, DEFAULT_EXPANSION_FACTOR};
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, b: u8, c: bool) -> u8 {
+        let d: u8 = a * b + a;
+        if c {
+            d = d - 1u8;
+        }
+        return d;
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}";
+
+#[test]
+fn test_expansion_is_recorded_per_function() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        // `main` gains temporaries for the binary expressions and literal, and a phi function for `d`.
+        let main = assigner.expansion(Symbol::intern("main")).unwrap();
+        assert_eq!(main.input, 4);
+        assert!(main.output > main.input, "{main:?}");
+        assert_eq!(assigner.expansion(Symbol::intern("helper")), Some(StatementExpansion { input: 1, output: 1 }));
+        assert!(frontend.warnings().is_empty());
+    })
+}
+
+#[test]
+fn test_expansion_above_threshold_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions { expansion_warning_threshold: Some(1.5), ..Default::default() };
+        frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Only `main` exceeds the threshold.
+        let warnings = frontend.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].to_string().contains("The function `main` expands from 4 to"));
+    })
+}
+
+#[test]
+fn test_output_size_is_estimated_from_input_statements() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+
+        // `main` has four statements and `helper` has one, scaled by the default factor.
+        let estimate = assigner.estimate_output_size(&frontend.program);
+        assert_eq!(estimate, (5.0 * DEFAULT_EXPANSION_FACTOR).ceil() as usize);
+
+        // The estimate bounds the size of the output of this program.
+        assigner.consume_program(frontend.program.clone());
+        let output: usize =
+            ["main", "helper"].iter().map(|name| assigner.expansion(Symbol::intern(name)).unwrap().output).sum();
+        assert!(output <= estimate, "{output} > {estimate}");
+    })
+}
+
+#[test]
+fn test_output_size_estimate_uses_the_configured_factor() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let assigner = frontend.assigner(SsaOptions { expansion_factor: Some(1.5), ..Default::default() });
+
+        assert_eq!(assigner.estimate_output_size(&frontend.program), 8);
+    })
+}
+
+#[test]
+fn test_temporaries_above_threshold_are_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions { temporary_warning_threshold: Some(3), ..Default::default() };
+        let mut assigner = frontend.assigner(options);
+        assigner.consume_program(frontend.program.clone());
+
+        // `a * b`, `a * b + a`, `1u8`, `d - 1u8`, and the phi function for `d` each introduce a temporary.
+        assert_eq!(assigner.temporary_count(Symbol::intern("main")), Some(5));
+        assert_eq!(assigner.temporary_count(Symbol::intern("helper")), Some(0));
+
+        // Only `main` exceeds the threshold.
+        let warnings = frontend.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].to_string().contains("The function `main` introduces 5 temporaries"), "{}", warnings[0]);
+    })
+}
+
+#[test]
+fn test_temporaries_above_budget_are_reported() {
+    create_session_if_not_set_then(|_| {
+        // Returns the errors emitted when consuming `PROGRAM` with a budget of `max_temporaries`.
+        let errors = |max_temporaries| {
+            let frontend = Frontend::new(PROGRAM);
+            frontend
+                .assigner(SsaOptions { max_temporaries, ..Default::default() })
+                .consume_program(frontend.program.clone());
+            frontend.errors()
+        };
+
+        // `main` introduces 5 temporaries, so a budget of 3 is exceeded once, by `main`.
+        let exceeded = errors(Some(3));
+        assert_eq!(exceeded.len(), 1);
+        assert!(
+            exceeded[0].to_string().contains("The function `main` exceeds the budget of 3 temporaries per function."),
+            "{}",
+            exceeded[0]
+        );
+        // A budget of 5 is not exceeded, and the default budget is unlimited.
+        assert!(errors(Some(5)).is_empty());
+        assert!(errors(None).is_empty());
+    })
+}
+
+#[test]
+fn test_temporaries_budget_applies_to_each_function() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition first(a: u8, b: u8) -> u8 {
+        let c: u8 = a * b + a;
+        return c;
+    }
+
+    transition second(a: u8, b: u8) -> u8 {
+        let c: u8 = a * b - a;
+        return c;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+        let (first, second) = (
+            assigner.temporary_count(Symbol::intern("first")).unwrap(),
+            assigner.temporary_count(Symbol::intern("second")).unwrap(),
+        );
+
+        // Each function is within the budget, although the program is not.
+        let budget = first.max(second);
+        assert!(first + second > budget);
+        frontend
+            .assigner(SsaOptions { max_temporaries: Some(budget), ..Default::default() })
+            .consume_program(frontend.program.clone());
+        assert!(frontend.errors().is_empty());
+
+        // Below the budget of each function, each function is reported.
+        frontend
+            .assigner(SsaOptions { max_temporaries: Some(budget - 1), ..Default::default() })
+            .consume_program(frontend.program.clone());
+        let errors: Vec<String> = frontend.errors().iter().map(|error| error.to_string()).collect();
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(
+            errors[0].contains("The function `first`") && errors[1].contains("The function `second`"),
+            "{errors:?}"
+        );
+    })
+}
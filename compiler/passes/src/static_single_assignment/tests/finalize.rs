@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, parses, Frontend};
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b then finalize(a);
+    }
+
+    finalize main(a: u8) {
+        let c: u8 = a + 1u8;
+        assert_eq(c, a);
+    }
+
+    transition other(a: u8) -> u8 {
+        let b: u8 = a * 2u8;
+        return b;
+    }
+}";
+
+#[test]
+fn test_only_finalize_blocks_are_consumed() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let program = frontend.assigner(SsaOptions::default()).consume_finalize_blocks(frontend.program.clone());
+
+        // The main blocks are unchanged.
+        for name in ["main", "other"] {
+            assert_eq!(function(&program, name).block, function(&frontend.program, name).block);
+        }
+
+        // The finalize block is in SSA form.
+        let finalize = function(&program, "main").finalize.as_ref().expect("`main` should have a finalize block.");
+        let original = function(&frontend.program, "main").finalize.as_ref().unwrap();
+        assert_ne!(finalize.block, original.block);
+        assert!(finalize.block.to_string().contains("c$"), "{}", finalize.block);
+    })
+}
+
+#[test]
+fn test_finalize_definitions_are_collected_per_function() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        return b then finalize(a);
+    }
+
+    finalize main(a: u8) {
+        let c: u8 = a;
+        let d: u8 = c;
+        if a == 0u8 {
+            d = a;
+        }
+        assert_eq(c, d);
+    }
+
+    transition other(a: u8) -> u8 {
+        let b: u8 = a;
+        return b;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        // Each version of `d` is listed, including the phi function, but the definition of `b` in the function block is not.
+        let definitions = assigner.finalize_definitions(Symbol::intern("main")).unwrap();
+        let originals: Vec<String> = definitions.iter().map(|record| record.original.to_string()).collect();
+        assert_eq!(originals, ["c", "d", "d", "d"]);
+        for record in definitions {
+            assert!(record.renamed.to_string().starts_with(&format!("{}$", record.original)), "{}", record.renamed);
+        }
+        assert!(assigner.finalize_definitions(Symbol::intern("other")).is_none());
+    })
+}
+
+#[test]
+fn test_named_outputs_are_rejected_by_the_parser() {
+    create_session_if_not_set_then(|_| {
+        // Outputs have no names for a finalize variable to shadow, so naming an output is a syntax error.
+        assert!(!parses(
+            "
+program test.aleo {
+    transition main(a: u8) -> (b: u8) {
+        return a then finalize(a);
+    }
+
+    finalize main(a: u8) {
+        let b: u8 = a + 1u8;
+        assert_eq(b, a);
+    }
+}"
+        ));
+    })
+}
+
+#[test]
+fn test_finalize_functions_include_imports_and_are_reset() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        // `test` imports a copy of its own program scope, named `other`, and declares no function with a finalize block itself.
+        let mut other = frontend.program.clone();
+        other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+        let mut program = frontend.program.clone();
+        program
+            .program_scopes
+            .values_mut()
+            .for_each(|scope| scope.functions.retain(|(_, function)| function.finalize.is_none()));
+        program.imports = [(Symbol::intern("other"), (other, Span::default()))].into_iter().collect();
+
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(program.clone());
+        assert_eq!(assigner.finalize_functions().iter().collect::<Vec<_>>(), [&Symbol::intern("main")]);
+
+        // The set is reset between programs.
+        program.imports.clear();
+        assigner.consume_program(program);
+        assert!(assigner.finalize_functions().is_empty());
+    })
+}
+
+#[test]
+fn test_finalize_blocks_are_consumed_in_their_program_scope() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut other = frontend.program.clone();
+        other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+
+        // The assigner is reused for a second program, whose finalize blocks are named after its own program scope.
+        let mut assigner = frontend.assigner(SsaOptions { prefix_scope_names: true, ..Default::default() });
+        assigner.consume_program(frontend.program.clone());
+        let program = assigner.consume_finalize_blocks(other);
+
+        let finalize = function(&program, "main").finalize.as_ref().expect("`main` should have a finalize block.");
+        let block = finalize.block.to_string();
+        assert!(block.contains("other$c$") && !block.contains("test$"), "{block}");
+        assert_eq!(assigner.finalize_functions().iter().collect::<Vec<_>>(), [&Symbol::intern("main")]);
+        assert!(frontend.errors().is_empty());
+    })
+}
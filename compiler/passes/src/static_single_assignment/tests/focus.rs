@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, Frontend};
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+#[test]
+fn test_only_the_focused_function_is_consumed() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b;
+    }
+
+    function helper(a: u8) -> u8 {
+        let b: u8 = a * 2u8;
+        return b;
+    }
+}",
+        );
+        let options = SsaOptions { focus: Some(Symbol::intern("helper")), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Note that functions are compared by their source, since `Function` only compares identifiers.
+        let block = |program, name| function(program, name).block.to_string();
+        assert_eq!(block(&program, "main"), block(&frontend.program, "main"));
+        assert_ne!(block(&program, "helper"), block(&frontend.program, "helper"));
+        assert!(function(&program, "helper").block.to_string().contains("b$"));
+    })
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+use indexmap::IndexSet;
+
+#[test]
+fn test_free_variables_of_nested_blocks_are_the_inputs() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, b: u8, c: bool) -> u8 {
+        let d: u8 = a;
+        if c {
+            let e: u8 = d + b;
+            if e == 0u8 {
+                d = e;
+            } else {
+                d = e + a;
+            }
+        }
+        return d;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        let free_variables =
+            assigner.free_variables(Symbol::intern("main")).expect("`main` should have been consumed.");
+        let inputs: IndexSet<Symbol> = ["a", "b", "c"].into_iter().map(Symbol::intern).collect();
+        assert_eq!(free_variables.iter().copied().collect::<IndexSet<_>>(), inputs);
+        assert!(assigner.free_variables(Symbol::intern("missing")).is_none());
+    })
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{SsaOptions, StaticSingleAssigner};
+
+use leo_ast::ProgramConsumer;
+use leo_errors::{
+    emitter::{Emitter, Handler},
+    LeoError,
+    LeoWarning,
+};
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::{cell::RefCell, rc::Rc};
+
+/// An `Emitter` that records the codes of the emitted diagnostics without reporting any error.
+#[derive(Clone, Default)]
+struct SuppressingEmitter(Rc<RefCell<Vec<String>>>);
+
+impl Emitter for SuppressingEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        self.0.borrow_mut().push(err.error_code());
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        None
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.0.borrow_mut().push(warning.error_code());
+    }
+}
+
+#[test]
+fn test_diagnostics_are_routed_through_the_given_emitter() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        amount: u64,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let emitter = SuppressingEmitter::default();
+        let handler = Handler::new(Box::new(emitter.clone()));
+        let mut assigner = StaticSingleAssigner::new(
+            &frontend.node_builder,
+            &frontend.symbol_table,
+            &frontend.type_table,
+            &frontend.assigner,
+            &handler,
+            SsaOptions::default(),
+        );
+        assigner.consume_program(frontend.program.clone());
+
+        // The missing `owner` is reported to the emitter, which suppresses it.
+        let codes = emitter.0.take();
+        assert_eq!(codes.len(), 1);
+        assert!(codes[0].starts_with("ESSA"), "{codes:?}");
+        assert!(handler.last_err().is_ok());
+        // The default buffer of the frontend is left untouched.
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_the_repro_isolates_the_function_reporting_an_error() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    transition first(a: u8) -> u8 {
+        let b: u8 = a;
+        return b;
+    }
+
+    transition check(a: address) -> bool {
+        assert_eq(self, a);
+        return true;
+    }
+
+    transition last(p: Point) -> u8 {
+        return p.x;
+    }
+}",
+        );
+        let (_, repro) = frontend.assigner(SsaOptions::default()).consume_program_with_repro(frontend.program.clone());
+
+        // Only the reference to `self` in `check` is reported, and the candidates report nothing.
+        assert_eq!(frontend.errors().len(), 1);
+        let repro = repro.expect("The program should report an error.");
+        let scope = repro.program_scopes.values().next().unwrap();
+        let functions: Vec<String> = scope.functions.iter().map(|(name, _)| name.to_string()).collect();
+        assert_eq!(functions, ["check"]);
+        assert_eq!(scope.structs.len(), 1);
+    })
+}
+
+#[test]
+fn test_no_repro_is_produced_without_errors() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let (_, repro) = frontend.assigner(SsaOptions::default()).consume_program_with_repro(frontend.program.clone());
+        assert!(repro.is_none());
+    })
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{rename_history_to_csv, RenameTransform, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = a;
+        }
+        return b;
+    }
+}";
+
+#[test]
+fn test_rename_history_records_scope_depths() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        // `let b` is in the block of the function, `b = a` is in the then-block, and the phi function follows the conditional.
+        let history = assigner.rename_history();
+        let rows: Vec<(String, usize)> =
+            history.iter().map(|record| (record.renamed.to_string(), record.scope_depth)).collect();
+        assert_eq!(rows, [("b$0".to_string(), 1), ("b$1".to_string(), 2), ("b$2".to_string(), 1)]);
+        assert!(history.iter().all(|record| record.program.to_string() == "test.aleo"
+            && record.function == Symbol::intern("main")
+            && record.original == Symbol::intern("b")));
+
+        assert_eq!(
+            rename_history_to_csv(history),
+            "program,function,original,renamed,scope_depth
+test.aleo,main,b,b$0,1
+test.aleo,main,b,b$1,2
+test.aleo,main,b,b$2,1
+"
+        );
+    })
+}
+
+#[test]
+fn test_rename_history_csv_escapes_fields() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions {
+            rename_transform: Some(RenameTransform::new(|symbol| Symbol::intern(&format!("{symbol},\"x\"")))),
+            ..Default::default()
+        };
+        let mut assigner = frontend.assigner(options);
+        assigner.consume_program(frontend.program.clone());
+
+        let csv = rename_history_to_csv(assigner.rename_history());
+        assert!(csv.contains("test.aleo,main,b,\"b$0,\"\"x\"\"\",1\n"), "{csv}");
+    })
+}
+
+const TRIVIAL: &str = "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = a;
+        }
+        return b;
+    }
+
+    function passthrough(a: u8, b: u8) -> u8 {
+        return a + b;
+    }
+}";
+
+#[test]
+fn test_functions_without_renamings_are_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(TRIVIAL);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        // The temporary introduced for `a + b` is not a renaming.
+        assert_eq!(assigner.renaming_count(Symbol::intern("passthrough")), Some(0));
+        assert!(assigner.renaming_count(Symbol::intern("main")).unwrap() > 0);
+        let unrenamed: Vec<String> = assigner.unrenamed_functions().iter().map(|name| name.to_string()).collect();
+        assert_eq!(unrenamed, ["passthrough"]);
+    })
+}
+
+#[test]
+fn test_processing_order_follows_the_input_order() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    function zeta(a: u8) -> u8 {
+        return a;
+    }
+
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        return b;
+    }
+
+    function alpha(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        // `test` imports a copy of its own program scope, named `other`.
+        let mut other = frontend.program.clone();
+        other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+        let mut program = frontend.program.clone();
+        program.imports = [(Symbol::intern("other"), (other.clone(), Span::default()))].into_iter().collect();
+
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(program);
+
+        // The functions of `other` are consumed first, and the functions of each scope in the order of its `functions`.
+        let expected: Vec<String> = [&other, &frontend.program]
+            .into_iter()
+            .flat_map(|program| program.program_scopes.values())
+            .flat_map(|scope| scope.functions.iter().map(|(name, _)| format!("{}::{name}", scope.program_id)))
+            .collect();
+        let order: Vec<String> = assigner.processing_order().iter().map(|name| name.to_string()).collect();
+        assert_eq!(order, expected);
+        assert_eq!(order[..3], ["other.aleo::zeta", "other.aleo::main", "other.aleo::alpha"]);
+    })
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, parses, Frontend};
+use crate::SsaOptions;
+
+use leo_ast::{ProgramConsumer, Statement};
+use leo_span::symbol::create_session_if_not_set_then;
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a + b;
+        c = c * 2u8;
+        return c;
+    }
+}";
+
+/// Returns the source of `main`, with each assignment in the transformed `main` of `PROGRAM` written as a definition.
+fn reparsable_source(options: SsaOptions) -> String {
+    let frontend = Frontend::new(PROGRAM);
+    let program = frontend.assigner(options).consume_program(frontend.program.clone());
+    let statements: Vec<String> = function(&program, "main")
+        .block
+        .statements
+        .iter()
+        .map(|statement| match statement {
+            // Each variable of `PROGRAM` has type `u8`.
+            Statement::Assign(assign) => format!("let {}: u8 = {};", assign.place, assign.value),
+            Statement::Return(return_) => format!("return {};", return_.expression),
+            _ => panic!("`main` should only contain assignments and a return."),
+        })
+        .collect();
+    format!("program test.aleo {{ transition main(a: u8, b: u8) -> u8 {{ {} }} }}", statements.join(" "))
+}
+
+#[test]
+fn test_valid_identifiers_reparse() {
+    create_session_if_not_set_then(|_| {
+        let source = reparsable_source(SsaOptions { valid_identifiers: true, ..Default::default() });
+        assert!(source.contains("c__"), "{source}");
+        assert!(!source.contains('$'), "{source}");
+        assert!(parses(&source), "{source}");
+    })
+}
+
+#[test]
+fn test_default_names_do_not_reparse() {
+    create_session_if_not_set_then(|_| {
+        let source = reparsable_source(SsaOptions::default());
+        assert!(!parses(&source), "{source}");
+    })
+}
+
+#[test]
+fn test_collision_with_source_name_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        let var__0: u8 = a + b;
+        return var__0;
+    }
+}",
+        );
+        // The temporary for `a + b` is the first name introduced by the pass.
+        frontend
+            .assigner(SsaOptions { valid_identifiers: true, ..Default::default() })
+            .consume_program(frontend.program.clone());
+
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("The name `var__0` introduced by the pass collides"), "{}", errors[0]);
+    })
+}
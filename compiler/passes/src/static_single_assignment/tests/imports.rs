@@ -0,0 +1,372 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{assigned_names, function, Frontend};
+use crate::{count_qualified_functions, qualified_functions, ScopeCache, SsaOptions};
+
+use leo_ast::{Program, ProgramConsumer};
+use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+use indexmap::IndexSet;
+
+/// Returns a program without program scopes that imports each of `imports`.
+fn importing(imports: Vec<(&str, Program)>) -> Program {
+    Program {
+        imports: imports.into_iter().map(|(name, import)| (Symbol::intern(name), (import, Span::default()))).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_import_graph_to_dot() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        // `test` imports `token` and `math`, `token` imports `math`, and `math` imports `token`, closing a cycle.
+        let mut program = frontend.program.clone();
+        let token = importing(vec![("math", importing(vec![("token", Program::default())]))]);
+        program.imports = importing(vec![("token", token), ("math", Program::default())]).imports;
+
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(program);
+
+        assert_eq!(
+            assigner.import_graph().to_dot(),
+            "digraph imports {
+    \"test\";
+    \"token\";
+    \"math\";
+    \"test\" -> \"token\";
+    \"token\" -> \"math\";
+    \"math\" -> \"token\" [style = dashed, color = red];
+    \"test\" -> \"math\";
+}"
+        );
+    })
+}
+
+#[test]
+fn test_deep_import_chain() {
+    create_session_if_not_set_then(|_| {
+        const DEPTH: usize = 2_000;
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        b = b + 1u8;
+        return b;
+    }
+}",
+        );
+        // `test` imports `p0`, which imports `p1`, and so on, where each import contains the program scope of `test`.
+        let mut chain = Program::default();
+        for depth in (0..DEPTH).rev() {
+            let mut import = frontend.program.clone();
+            import.imports = importing(vec![(&format!("p{depth}"), chain)]).imports;
+            chain = import;
+        }
+
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let (program, summary) = assigner.consume_program_with_summary(chain);
+        assert_eq!(summary.imports, DEPTH);
+        assert_eq!(assigner.import_graph().imports().len(), DEPTH);
+
+        // Each program in the chain is consumed and kept in place.
+        let mut program = &program;
+        let mut depth = 0;
+        while let Some((name, (import, _))) = program.imports.first() {
+            assert_eq!(name.to_string(), format!("p{depth}"));
+            assert!(function(program, "main").block.to_string().contains("b$"));
+            program = import;
+            depth += 1;
+        }
+        assert_eq!(depth, DEPTH);
+    })
+}
+
+#[test]
+fn test_qualified_functions_of_imports() {
+    create_session_if_not_set_then(|_| {
+        let source = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b;
+    }
+}";
+        let frontend = Frontend::new(source);
+        let token = Frontend::new(&source.replace("test.aleo", "token.aleo"));
+        // `test` imports `token` twice, directly and through `math`.
+        let mut program = frontend.program.clone();
+        let math = importing(vec![("token", token.program.clone())]);
+        program.imports = importing(vec![("token", token.program.clone()), ("math", math)]).imports;
+
+        let program = frontend.assigner(SsaOptions::default()).consume_program(program);
+        let functions = qualified_functions(&program);
+
+        // The functions named `main` are distinguished by their programs.
+        let names: Vec<String> = functions.iter().map(|(name, _)| name.to_string()).collect();
+        assert_eq!(names, ["test.aleo::main", "token.aleo::main"]);
+        assert_eq!(count_qualified_functions(&program), functions.len());
+        assert!(functions.iter().all(|(_, function)| function.block.to_string().contains("b$")));
+    })
+}
+
+#[test]
+fn test_self_import_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        // `test` imports `math`, which is fine, and `test`, which is not.
+        let mut program = frontend.program.clone();
+        program.imports = importing(vec![("math", Program::default()), ("test", Program::default())]).imports;
+
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(program);
+
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("The program `test` imports itself."), "{}", errors[0]);
+        // The self-import is also a cycle in the import graph.
+        assert!(assigner.import_graph().imports().iter().any(|import| import.is_cycle));
+    })
+}
+
+#[test]
+fn test_records_of_imports_are_consumed_in_canonical_layout() {
+    create_session_if_not_set_then(|_| {
+        let source = |program: &str, record: &str| {
+            format!(
+                "
+program {program}.aleo {{
+    record {record} {{
+        amount: u64,
+        owner: address,
+    }}
+
+    struct Point {{
+        y: u8,
+        x: u8,
+    }}
+
+    transition main(a: u8) -> u8 {{
+        let b: u8 = a + 1u8;
+        return b;
+    }}
+}}"
+            )
+        };
+        let frontend = Frontend::new(&source("test", "Receipt"));
+        let token = Frontend::new(&source("token", "Token"));
+        // `test` imports `token` both directly and through `bank`, which declares no records.
+        let bank = importing(vec![("token", token.program.clone())]);
+        let mut program = frontend.program.clone();
+        program.imports = importing(vec![("token", token.program.clone()), ("bank", bank)]).imports;
+
+        let records = frontend.assigner(SsaOptions::default()).consume_records(program);
+
+        let names: Vec<String> = records.keys().map(|record| record.to_string()).collect();
+        assert_eq!(names, ["token.aleo/Token", "test.aleo/Receipt"]);
+        for record in records.values() {
+            let fields: Vec<String> = record.members.iter().map(|member| member.name().to_string()).collect();
+            assert_eq!(fields, ["owner", "amount"]);
+        }
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_prefixed_scope_names_are_disjoint() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        b = b * 2u8;
+        return b;
+    }
+}",
+        );
+        // `test` imports a copy of its own program scope, named `other`.
+        let mut other = frontend.program.clone();
+        other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+        let mut program = frontend.program.clone();
+        program.imports = importing(vec![("other", other)]).imports;
+
+        let options = SsaOptions { prefix_scope_names: true, ..Default::default() };
+        let program = frontend.assigner(options).consume_program(program);
+
+        let names = assigned_names(&function(&program, "main").block);
+        let (other, _) = &program.imports[&Symbol::intern("other")];
+        let other_names = assigned_names(&function(other, "main").block);
+        assert!(!names.is_empty() && !other_names.is_empty());
+        assert!(names.iter().all(|name| name.starts_with("test$")), "{names:?}");
+        assert!(other_names.iter().all(|name| name.starts_with("other$")), "{other_names:?}");
+        assert!(names.iter().all(|name| !other_names.contains(name)));
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_hoists_are_reported_across_imports() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    record Ticket {
+        owner: address,
+        id: u64,
+    }
+
+    record Badge {
+        id: u64,
+        level: u8,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        // `test` imports a copy of its own program scope, named `other`.
+        let mut other = frontend.program.clone();
+        other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+        let mut program = frontend.program.clone();
+        program.imports = importing(vec![("other", other)]).imports;
+
+        let (_, hoists) = frontend.assigner(SsaOptions::default()).consume_program_with_hoists(program);
+
+        // The canonical record `Ticket` contributes nothing.
+        let hoists: Vec<String> = hoists.iter().map(|hoist| hoist.to_string()).collect();
+        assert_eq!(hoists, [
+            "other.aleo/Token: `owner` moved from position 2 to 1",
+            "other.aleo/Badge: `owner` moved from position 3 to 1",
+            "test.aleo/Token: `owner` moved from position 2 to 1",
+            "test.aleo/Badge: `owner` moved from position 3 to 1",
+        ]);
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_incremental_consumption_recomputes_only_dependents() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b;
+    }
+}",
+        );
+        // Returns a copy of the program scope of `test`, named `name`, that imports each of `imports`.
+        let copy = |name: &str, imports: Vec<(&str, Program)>| {
+            let mut program = frontend.program.clone();
+            program.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern(name));
+            program.imports = importing(imports).imports;
+            program
+        };
+        // `test` imports `bank` and `math`, and `bank` imports `token`.
+        let program = || {
+            let bank = copy("bank", vec![("token", copy("token", vec![]))]);
+            let mut program = frontend.program.clone();
+            program.imports = importing(vec![("bank", bank), ("math", copy("math", vec![]))]).imports;
+            program
+        };
+        let names = |names: &IndexSet<Symbol>| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+        // The first consumption fills the cache.
+        let mut cache = ScopeCache::default();
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let (first, consumed) =
+            assigner.consume_program_incrementally(program(), Symbol::intern("test"), &Default::default(), &mut cache);
+        assert_eq!(names(&consumed), ["token", "bank", "math", "test"]);
+        assert_eq!(cache.len(), 4);
+        let graph = assigner.import_graph().clone();
+
+        // Changing `token` recomputes `token` and its dependents, but `math` is reused from the cache.
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let (second, consumed) =
+            assigner.consume_program_incrementally(program(), Symbol::intern("token"), &graph, &mut cache);
+        assert_eq!(names(&consumed), ["token", "bank", "test"]);
+        let (math, _) = &second.imports[&Symbol::intern("math")];
+        let (cached, _) = &first.imports[&Symbol::intern("math")];
+        assert_eq!(math, cached);
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_function_limit_counts_the_functions_of_imports() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b;
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        // `test` imports a copy of its own program scope, named `other`, for four functions in total.
+        let mut other = frontend.program.clone();
+        other.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern("other"));
+        let mut program = frontend.program.clone();
+        program.imports = importing(vec![("other", other)]).imports;
+
+        // The program is within the limit, counting the functions of `other`.
+        frontend.assigner(SsaOptions { max_functions: Some(4), ..Default::default() }).consume_program(program.clone());
+        assert!(frontend.errors().is_empty());
+
+        // The program exceeds the limit, so it is reported and returned unchanged.
+        let mut assigner = frontend.assigner(SsaOptions { max_functions: Some(3), ..Default::default() });
+        assigner.consume_program(frontend.program.clone());
+        assert_eq!(assigner.processing_order().len(), 2);
+        let output = assigner.consume_program(program.clone());
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("declares 4 functions"), "{}", errors[0]);
+        assert_eq!(output.to_string(), program.to_string());
+        // The state of the previously consumed program is reset.
+        assert!(assigner.processing_order().is_empty());
+        assert_eq!(assigner.summary.functions, 0);
+    })
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{SsaOptions, SymbolResolver};
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        a = a + 1u8;
+        return a;
+    }
+
+    function helper(constant b: u8, c: u8) -> u8 {
+        c = c + b;
+        b = c;
+        return b;
+    }
+}";
+
+#[test]
+fn test_constant_input_reassignment_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions { check_constant_input_reassignment: true, ..Default::default() };
+        frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Only the constant input `b` is reported.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("The constant input `b` is reassigned."));
+    })
+}
+
+#[test]
+fn test_constant_input_reassignment_is_not_reported_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+const SHADOWING: &str = "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        let a: u8 = b + 1u8;
+        b = a;
+        return b;
+    }
+}";
+
+#[test]
+fn test_pinned_input_redefinition_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(SHADOWING);
+        let options = SsaOptions { pin_input_names: true, ..Default::default() };
+        frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Only the definition of `a` is reported, the assignment to `b` is allowed.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("The definition of `a` reuses the name of an input"));
+    })
+}
+
+#[test]
+fn test_input_redefinition_is_renamed_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(SHADOWING);
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_shadowed_input_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(SHADOWING);
+        let options = SsaOptions { warn_shadowed_inputs: true, ..Default::default() };
+        frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Only the definition of `a` is reported, the assignment to `b` is allowed.
+        let warnings = frontend.warnings();
+        assert_eq!(warnings.len(), 1);
+        // The warning points at the shadowing definition, and is labelled with the span of the input.
+        let warning = warnings[0].to_string();
+        assert!(warning.contains("The definition of `a` shadows an input."), "{warning}");
+        assert!(warning.contains("--> test:4:13"), "{warning}");
+        assert!(warning.contains("--> test:3:21"), "{warning}");
+        assert!(warning.contains("^ the input is declared here"), "{warning}");
+    })
+}
+
+#[test]
+fn test_shadowed_input_is_not_reported_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(SHADOWING);
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        assert!(frontend.warnings().is_empty());
+    })
+}
+
+#[test]
+fn test_diagnostics_use_the_symbol_resolver() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let resolver = SymbolResolver::new(|symbol| format!("<{}>", symbol.to_string().to_uppercase()));
+        let options = SsaOptions {
+            check_constant_input_reassignment: true,
+            symbol_resolver: Some(resolver),
+            ..Default::default()
+        };
+        frontend.assigner(options).consume_program(frontend.program.clone());
+
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("The constant input `<B>` is reassigned."), "{}", errors[0]);
+    })
+}
+
+#[test]
+fn test_input_shadowed_in_nested_scope_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        if b > 1u8 {
+            let a: u8 = b + 1u8;
+            b = a;
+        }
+        return b;
+    }
+}",
+        );
+        let options = SsaOptions { warn_shadowed_inputs: true, ..Default::default() };
+        frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // The input is found in the outermost scope of the function.
+        let warnings = frontend.warnings();
+        assert_eq!(warnings.len(), 1);
+        let warning = warnings[0].to_string();
+        assert!(warning.contains("--> test:5:17"), "{warning}");
+        assert!(warning.contains("--> test:3:21"), "{warning}");
+    })
+}
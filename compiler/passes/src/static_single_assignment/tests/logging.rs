@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use super::Frontend;
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event,
+    Level,
+    Metadata,
+    Subscriber,
+};
+
+/// A subscriber collecting the fields of the trace events of the pass, as `(function, original, renamed)`.
+#[derive(Clone, Default)]
+struct Renamings(Arc<Mutex<Vec<(String, String, String)>>>);
+
+#[derive(Default)]
+struct Fields(Vec<(String, String)>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+impl Subscriber for Renamings {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "leo_passes::ssa" && *metadata.level() == Level::TRACE
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let field =
+            |name: &str| fields.0.iter().find(|(field, _)| field == name).map(|(_, value)| value.clone()).unwrap();
+        self.0.lock().unwrap().push((field("function"), field("original"), field("renamed")));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        b = b + 1u8;
+        return b;
+    }
+}";
+
+#[test]
+fn test_renamings_are_traced() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let renamings = Renamings::default();
+        tracing::subscriber::with_default(renamings.clone(), || {
+            frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+        });
+
+        // The temporaries for `1u8` and `b + 1u8` are not renamings, so they are not traced.
+        let events = renamings.0.lock().unwrap().clone();
+        let expected = [("main", "b", "b$0"), ("main", "b", "b$3")];
+        let expected: Vec<(String, String, String)> = expected
+            .iter()
+            .map(|(function, original, renamed)| (function.to_string(), original.to_string(), renamed.to_string()))
+            .collect();
+        assert_eq!(events, expected);
+    })
+}
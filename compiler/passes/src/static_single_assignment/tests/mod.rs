@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod binary;
+mod builder;
+
+mod cache;
+
+mod control_flow;
+mod coverage;
+
+mod deadline;
+
+mod determinism;
+
+mod expansion;
+
+mod finalize;
+
+mod focus;
+
+mod free_variables;
+
+mod handler;
+
+mod history;
+
+mod identifiers;
+
+mod imports;
+
+mod inputs;
+
+mod logging;
+
+mod mutation;
+
+mod numbering;
+
+mod observer;
+
+mod paired;
+
+mod records;
+
+mod rename_tables;
+
+mod renaming;
+
+mod scope_stack;
+
+mod scoping;
+
+mod sexpr;
+
+mod signatures;
+
+mod summary;
+
+mod targets;
+
+mod timeline;
+
+mod trivia;
+
+mod unreferenced;
+
+mod validation;
+
+use crate::{Assigner, SsaOptions, StaticSingleAssigner, SymbolTable, SymbolTableCreator, TypeChecker, TypeTable};
+
+use leo_ast::{Block, Expression, Function, NodeBuilder, Program, ProgramConsumer, ProgramVisitor, Statement};
+use leo_errors::{
+    emitter::{BufferEmitter, Handler},
+    LeoError,
+    LeoWarning,
+};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+/// The state produced by the passes preceding static single assignment.
+/// Note that constructing a `Frontend` requires an active session, e.g. via `create_session_if_not_set_then`.
+pub(crate) struct Frontend {
+    /// The parsed and type checked program.
+    pub(crate) program: Program,
+    /// The symbol table produced by type checking.
+    pub(crate) symbol_table: SymbolTable,
+    /// The type table produced by type checking.
+    pub(crate) type_table: TypeTable,
+    /// The node builder used to parse the program.
+    pub(crate) node_builder: NodeBuilder,
+    /// The assigner used by the pass.
+    pub(crate) assigner: Assigner,
+    /// The handler used by the pass.
+    pub(crate) handler: Handler,
+    /// The buffer collecting the diagnostics emitted by the pass.
+    pub(crate) buffer: BufferEmitter,
+}
+
+impl Frontend {
+    /// Parses `source` and runs symbol table creation and type checking on it.
+    /// Note that any errors emitted by these passes are discarded, so that malformed programs can still reach the pass.
+    pub(crate) fn new(source: &str) -> Self {
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let (frontend_handler, _) = Handler::new_with_buf();
+
+        // Parse the program.
+        let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("test".into())));
+        let program = leo_parser::parse_ast(&frontend_handler, &node_builder, &source_file.src, source_file.start_pos)
+            .expect("The test program should parse.")
+            .into_repr();
+
+        // Create the symbol table.
+        let mut creator = SymbolTableCreator::new(&frontend_handler);
+        creator.visit_program(&program);
+        let symbol_table = creator.symbol_table;
+
+        // Type check the program.
+        let mut checker = TypeChecker::new(symbol_table, &type_table, &frontend_handler);
+        checker.visit_program(&program);
+        let symbol_table = checker.symbol_table.take();
+
+        let (handler, buffer) = Handler::new_with_buf();
+
+        Self { program, symbol_table, type_table, node_builder, assigner: Assigner::default(), handler, buffer }
+    }
+
+    /// Returns a `StaticSingleAssigner` over the state of the frontend, configured with `options`.
+    pub(crate) fn assigner(&self, options: SsaOptions) -> StaticSingleAssigner<'_> {
+        StaticSingleAssigner::new(
+            &self.node_builder,
+            &self.symbol_table,
+            &self.type_table,
+            &self.assigner,
+            &self.handler,
+            options,
+        )
+    }
+
+    /// Returns the printed output of the pass over the program, configured with `options`.
+    /// The assigner borrows the frontend, so it is dropped here rather than outliving a frontend local to the caller.
+    pub(crate) fn output(&self, options: SsaOptions) -> String {
+        let mut assigner = self.assigner(options);
+        let output = assigner.consume_program(self.program.clone()).to_string();
+        drop(assigner);
+        output
+    }
+
+    /// Extracts the errors emitted by the pass thus far.
+    pub(crate) fn errors(&self) -> Vec<LeoError> {
+        self.buffer.extract_errs().into_inner()
+    }
+
+    /// Extracts the warnings emitted by the pass thus far.
+    pub(crate) fn warnings(&self) -> Vec<LeoWarning> {
+        self.buffer.extract_warnings().into_inner()
+    }
+}
+
+/// Returns `true` if `source` parses as a Leo program.
+pub(crate) fn parses(source: &str) -> bool {
+    let (handler, _) = Handler::new_with_buf();
+    let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("parsed".into())));
+    leo_parser::parse_ast(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos).is_ok()
+}
+
+/// Returns the names of the members of the struct named `name` in the first program scope of `program`.
+pub(crate) fn member_names(program: &Program, name: &str) -> Vec<String> {
+    let scope = program.program_scopes.values().next().expect("The test program should have a program scope.");
+    let (_, struct_) = scope
+        .structs
+        .iter()
+        .find(|(symbol, _)| symbol.to_string() == name)
+        .unwrap_or_else(|| panic!("The test program should declare `{name}`."));
+    struct_.members.iter().map(|member| member.name().to_string()).collect()
+}
+
+/// Returns the function named `name` in the first program scope of `program`.
+pub(crate) fn function<'a>(program: &'a Program, name: &str) -> &'a Function {
+    let scope = program.program_scopes.values().next().expect("The test program should have a program scope.");
+    let (_, function) = scope
+        .functions
+        .iter()
+        .find(|(symbol, _)| symbol.to_string() == name)
+        .unwrap_or_else(|| panic!("The test program should declare `{name}`."));
+    function
+}
+
+/// Returns the names assigned in `block` and its nested blocks, in order.
+pub(crate) fn assigned_names(block: &Block) -> Vec<String> {
+    let mut names = Vec::new();
+    block.statements.iter().for_each(|statement| collect_assigned_names(statement, &mut names));
+    names
+}
+
+fn collect_assigned_names(statement: &Statement, names: &mut Vec<String>) {
+    match statement {
+        Statement::Assign(assign) => match &assign.place {
+            Expression::Identifier(identifier) => names.push(identifier.name.to_string()),
+            Expression::Tuple(tuple) => names.extend(tuple.elements.iter().map(|element| element.to_string())),
+            _ => {}
+        },
+        Statement::Block(block) => names.extend(assigned_names(block)),
+        Statement::Conditional(conditional) => {
+            names.extend(assigned_names(&conditional.then));
+            if let Some(otherwise) = &conditional.otherwise {
+                collect_assigned_names(otherwise, names);
+            }
+        }
+        _ => {}
+    }
+}
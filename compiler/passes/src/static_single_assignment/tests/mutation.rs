@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{MutationKind, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+#[test]
+fn test_mutation_points_of_small_program() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(flag: bool, a: u8) -> u8 {
+        let b: u8 = a;
+        b = b + 1u8;
+        if flag {
+            b = b * 2u8;
+        }
+        return b;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+        let points = assigner.mutation_points(&program);
+
+        // The phi function for `b` selects between the versions of `b` assigned in and before the then-block.
+        let phis: Vec<_> =
+            points.iter().filter(|point| matches!(point.kind, MutationKind::SwapPhiArguments { .. })).collect();
+        assert_eq!(phis.len(), 1, "{points:?}");
+        assert_eq!(phis[0].kind, MutationKind::SwapPhiArguments { variable: Symbol::intern("b") });
+
+        // Each use of `b` after its second version could be swapped with another version: the use in the then-block,
+        // the two arguments of the phi function, and the return.
+        let swaps: Vec<(Symbol, Symbol)> = points
+            .iter()
+            .filter_map(|point| match point.kind {
+                MutationKind::SwapVersion { name, alternative } => Some((name, alternative)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(swaps.len(), 4, "{points:?}");
+        for (name, alternative) in swaps {
+            assert_ne!(name, alternative);
+            assert_eq!(assigner.original_name(name), Some(Symbol::intern("b")));
+            assert_eq!(assigner.original_name(alternative), Some(Symbol::intern("b")));
+        }
+        assert!(points.iter().all(|point| point.function == Symbol::intern("main")));
+        assert!(points[0].to_string().starts_with("main: replace `b$"), "{}", points[0]);
+    })
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{assigned_names, function, Frontend};
+use crate::{NumberingScheme, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use indexmap::IndexSet;
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, b: u8, c: bool) -> u8 {
+        let d: u8 = a;
+        if c {
+            let e: u8 = d + b;
+            if e == b {
+                d = e;
+            } else {
+                d = a;
+            }
+        }
+        return d;
+    }
+}";
+
+/// Returns the names assigned in `main` when numbered with `numbering`.
+fn names_with(numbering: NumberingScheme) -> Vec<String> {
+    let frontend = Frontend::new(PROGRAM);
+    let program =
+        frontend.assigner(SsaOptions { numbering, ..Default::default() }).consume_program(frontend.program.clone());
+    assigned_names(&function(&program, "main").block)
+}
+
+#[test]
+fn test_per_block_numbering_preserves_uniqueness() {
+    create_session_if_not_set_then(|_| {
+        let per_function = names_with(NumberingScheme::PerFunction);
+        let per_block = names_with(NumberingScheme::PerBlock);
+
+        // Both schemes introduce the same number of names, all of which are unique.
+        assert_eq!(per_function.len(), per_block.len());
+        assert_eq!(per_function.iter().collect::<IndexSet<_>>().len(), per_function.len(), "{per_function:?}");
+        assert_eq!(per_block.iter().collect::<IndexSet<_>>().len(), per_block.len(), "{per_block:?}");
+
+        // The per-function scheme uses a single counter.
+        assert!(per_function.contains(&"d$0".to_string()), "{per_function:?}");
+        // The per-block scheme encodes the path of the block.
+        assert!(per_block.contains(&"d$0$0".to_string()), "{per_block:?}");
+        assert!(per_block.iter().any(|name| name.starts_with("e$0_0$")), "{per_block:?}");
+        assert!(per_block.iter().any(|name| name.starts_with("d$0_0_0$")), "{per_block:?}");
+        assert!(per_block.iter().any(|name| name.starts_with("d$0_0_1$")), "{per_block:?}");
+    })
+}
+
+/// The same program as `PROGRAM`, with the variables `d` and `e` named `total` and `sum`.
+const RENAMED: &str = "
+program test.aleo {
+    transition main(a: u8, b: u8, c: bool) -> u8 {
+        let total: u8 = a;
+        if c {
+            let sum: u8 = total + b;
+            if sum == b {
+                total = sum;
+            } else {
+                total = a;
+            }
+        }
+        return total;
+    }
+}";
+
+#[test]
+fn test_canonical_numbering_identifies_alpha_equivalent_programs() {
+    create_session_if_not_set_then(|_| {
+        // Returns the output of `source` when numbered with `numbering`.
+        let output = |source: &str, numbering: NumberingScheme| {
+            Frontend::new(source).output(SsaOptions { numbering, ..Default::default() })
+        };
+
+        let canonical = output(PROGRAM, NumberingScheme::Canonical);
+        assert_eq!(canonical, output(RENAMED, NumberingScheme::Canonical));
+        assert!(canonical.contains("$v$"), "{canonical}");
+        // The default numbering keeps the source names, so the outputs differ.
+        assert_ne!(output(PROGRAM, NumberingScheme::PerFunction), output(RENAMED, NumberingScheme::PerFunction));
+        // Programs that differ in more than the names of their variables still differ.
+        assert_ne!(canonical, output(&PROGRAM.replace("d = a;", "d = b;"), NumberingScheme::Canonical));
+    })
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{ProgressEvent, SsaObserver, SsaOptions};
+
+use leo_ast::{Program, ProgramConsumer};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+use std::{cell::RefCell, rc::Rc, sync::mpsc::channel};
+
+/// An `SsaObserver` that records each notification.
+#[derive(Clone, Default)]
+struct RecordingObserver(Rc<RefCell<Vec<String>>>);
+
+impl SsaObserver for RecordingObserver {
+    fn enter_function(&mut self, function: Symbol) {
+        self.0.borrow_mut().push(format!("enter {function}"));
+    }
+
+    fn leave_function(&mut self, function: Symbol) {
+        self.0.borrow_mut().push(format!("leave {function}"));
+    }
+
+    fn enter_finalize(&mut self, function: Symbol) {
+        self.0.borrow_mut().push(format!("enter finalize {function}"));
+    }
+
+    fn leave_finalize(&mut self, function: Symbol) {
+        self.0.borrow_mut().push(format!("leave finalize {function}"));
+    }
+}
+
+#[test]
+fn test_observer_is_notified_of_finalize_blocks() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a then finalize(a);
+    }
+
+    finalize main(a: u8) {
+        assert_eq(a, a);
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let observer = RecordingObserver::default();
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.set_observer(observer.clone());
+        assigner.consume_program(frontend.program.clone());
+
+        assert_eq!(*observer.0.borrow(), [
+            "enter main",
+            "leave main",
+            "enter finalize main",
+            "leave finalize main",
+            "enter helper",
+            "leave helper"
+        ]);
+    })
+}
+
+#[test]
+fn test_progress_events_are_sent_over_a_channel() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a then finalize(a);
+    }
+
+    finalize main(a: u8) {
+        assert_eq(a, a);
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let (sender, receiver) = channel();
+        let observer = RecordingObserver::default();
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.set_observer(observer.clone());
+        assigner.consume_program_with_progress(frontend.program.clone(), sender);
+
+        let (main, helper) = (Symbol::intern("main"), Symbol::intern("helper"));
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [
+            ProgressEvent::FunctionStarted { function: main, index: 0, total: 2 },
+            ProgressEvent::FunctionFinished { function: main, finished: 1, total: 2 },
+            ProgressEvent::FinalizeStarted { function: main },
+            ProgressEvent::FinalizeFinished { function: main },
+            ProgressEvent::FunctionStarted { function: helper, index: 1, total: 2 },
+            ProgressEvent::FunctionFinished { function: helper, finished: 2, total: 2 },
+        ]);
+        // The observer is only displaced while the program is consumed.
+        assert!(observer.0.borrow().is_empty());
+        assigner.consume_program(frontend.program.clone());
+        assert!(!observer.0.borrow().is_empty());
+    })
+}
+
+#[test]
+fn test_progress_total_counts_the_selected_functions_as_they_are_consumed() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let shared = Frontend::new(
+            "
+program shared.aleo {
+    function double(a: u8) -> u8 {
+        return a + a;
+    }
+}",
+        );
+        // `test` imports `left` and `right`, which both import `shared`.
+        let mut program = frontend.program.clone();
+        let importing_shared = || Program {
+            imports: [(Symbol::intern("shared"), (shared.program.clone(), Default::default()))].into_iter().collect(),
+            ..Default::default()
+        };
+        program.imports.insert(Symbol::intern("left"), (importing_shared(), Default::default()));
+        program.imports.insert(Symbol::intern("right"), (importing_shared(), Default::default()));
+
+        // Returns the totals of the progress events, along with the number of functions started.
+        let run = |options| {
+            let (sender, receiver) = channel();
+            frontend.assigner(options).consume_program_with_progress(program.clone(), sender);
+            let events: Vec<ProgressEvent> = receiver.try_iter().collect();
+            let started = events.iter().filter(|event| matches!(event, ProgressEvent::FunctionStarted { .. })).count();
+            let totals: Vec<usize> = events
+                .iter()
+                .filter_map(|event| match event {
+                    ProgressEvent::FunctionStarted { total, .. } | ProgressEvent::FunctionFinished { total, .. } => {
+                        Some(*total)
+                    }
+                    _ => None,
+                })
+                .collect();
+            (totals, started)
+        };
+
+        // `double` is consumed once per import.
+        let (totals, started) = run(SsaOptions::default());
+        assert_eq!(started, 4);
+        assert!(totals.iter().all(|total| *total == 4), "{totals:?}");
+
+        // Only the focused function is counted.
+        let (totals, started) = run(SsaOptions { focus: Some(Symbol::intern("double")), ..Default::default() });
+        assert_eq!(started, 2);
+        assert!(totals.iter().all(|total| *total == 2), "{totals:?}");
+    })
+}
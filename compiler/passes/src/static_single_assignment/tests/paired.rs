@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+#[test]
+fn test_paired_consumption_returns_the_original() {
+    create_session_if_not_set_then(|_| {
+        let source = "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a + b;
+        return c;
+    }
+}";
+        let frontend = Frontend::new(source);
+        let (original, transformed) =
+            frontend.assigner(SsaOptions::default()).consume_program_paired(frontend.program.clone());
+        // Note that programs are compared by their source, since `Function` only compares identifiers.
+        assert_eq!(original.to_string(), frontend.program.to_string());
+
+        // The transformed program matches the output of `consume_program`.
+        let other = Frontend::new(source);
+        let expected = other.assigner(SsaOptions::default()).consume_program(other.program.clone());
+        assert_eq!(transformed.to_string(), expected.to_string());
+        assert_ne!(transformed.to_string(), original.to_string());
+    })
+}
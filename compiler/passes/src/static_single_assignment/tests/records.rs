@@ -0,0 +1,1183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, member_names, parses, Frontend};
+use crate::{
+    diff_record_layouts,
+    record_layouts,
+    FieldOrder,
+    LayoutResolver,
+    RecordLayout,
+    RenameTransform,
+    SsaOptions,
+    StructRewriter,
+};
+
+use leo_ast::{Expression, Identifier, IntegerType, Literal, Member, Mode, Program, ProgramConsumer, Statement, Type};
+use leo_errors::LeoError;
+use leo_span::{sym, symbol::create_session_if_not_set_then, Symbol};
+
+use indexmap::IndexMap;
+
+const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+        data: u8,
+        gates: u64,
+        flag: bool,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}";
+
+#[test]
+fn test_default_layout_hoists_owner() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "data", "gates", "flag"]);
+    })
+}
+
+#[test]
+fn test_owner_gates_layout() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options =
+            SsaOptions { record_layout: RecordLayout::new(vec![sym::owner, sym::gates]), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "gates", "amount", "data", "flag"]);
+    })
+}
+
+#[test]
+fn test_fields_resembling_reserved_fields_are_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        owner_id: u64,
+        owners: u8,
+        amount: u64,
+    }
+
+    struct Account {
+        owner_id: u64,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        frontend
+            .assigner(SsaOptions { warn_near_reserved_fields: true, ..Default::default() })
+            .consume_program(frontend.program.clone());
+
+        // Only the near misses of the record are reported, not the exact match or the fields of the struct.
+        let warnings = frontend.warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].to_string().contains("The field `owner_id` of the record `Token`"));
+        assert!(warnings[1].to_string().contains("The field `owners` of the record `Token`"));
+    })
+}
+
+#[test]
+fn test_fields_resembling_reserved_fields_are_not_reported_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        assert!(frontend.warnings().is_empty());
+    })
+}
+
+#[test]
+fn test_gates_owner_layout() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options =
+            SsaOptions { record_layout: RecordLayout::new(vec![sym::gates, sym::owner]), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // The remaining fields follow in their original order.
+        assert_eq!(member_names(&program, "Token"), ["gates", "owner", "amount", "data", "flag"]);
+    })
+}
+
+#[test]
+fn test_missing_reserved_fields_are_all_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record First {
+        amount: u64,
+    }
+
+    record Second {
+        data: u8,
+        flag: bool,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        // Both records are reported, rather than only the first.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].to_string().contains("`First`"));
+        assert!(errors[1].to_string().contains("`Second`"));
+
+        // The traversal continues past the errors.
+        assert_eq!(member_names(&program, "Second"), ["data", "flag"]);
+    })
+}
+
+#[test]
+fn test_duplicate_reserved_fields_are_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("`Token` declares the reserved field `owner` more than once"));
+    })
+}
+
+#[test]
+fn test_reordering_preserves_member_types() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options =
+            SsaOptions { record_layout: RecordLayout::new(vec![sym::gates, sym::owner]), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Only the positions of the members change.
+        let types = |program: &Program| {
+            let scope = program.program_scopes.values().next().unwrap();
+            let (_, token) = scope.structs.iter().find(|(name, _)| name.to_string() == "Token").unwrap();
+            token.members.iter().map(|member| (member.name(), member.type_.clone())).collect::<IndexMap<_, _>>()
+        };
+        assert_eq!(types(&program), types(&frontend.program));
+        assert_ne!(member_names(&program, "Token"), member_names(&frontend.program, "Token"));
+    })
+}
+
+/// Returns the errors emitted when consuming `source` with `layout`.
+fn missing_field_errors(source: &str, layout: RecordLayout) -> Vec<LeoError> {
+    let frontend = Frontend::new(source);
+    frontend
+        .assigner(SsaOptions { record_layout: layout, ..Default::default() })
+        .consume_program(frontend.program.clone());
+    frontend.errors()
+}
+
+#[test]
+fn test_missing_owner_and_gates_are_distinct_errors() {
+    create_session_if_not_set_then(|_| {
+        let layout = RecordLayout::new(vec![sym::owner, sym::gates]);
+        let without_owner = missing_field_errors(
+            "
+program test.aleo {
+    record Token {
+        gates: u64,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+            layout.clone(),
+        );
+        let without_gates = missing_field_errors(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+            layout,
+        );
+
+        assert_eq!(without_owner.len(), 1);
+        assert!(without_owner[0].to_string().contains("The record `Token` does not have an `owner` field."));
+        assert_eq!(without_gates.len(), 1);
+        assert!(without_gates[0].to_string().contains("The record `Token` does not have a `gates` field."));
+        assert_ne!(without_owner[0].error_code(), without_gates[0].error_code());
+    })
+}
+
+#[test]
+fn test_alphabetical_layout_is_canonical() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record First {
+        flag: bool,
+        owner: address,
+        amount: u64,
+        data: u8,
+    }
+
+    record Second {
+        data: u8,
+        amount: u64,
+        flag: bool,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let options = SsaOptions {
+            record_layout: RecordLayout::default().with_remaining_order(FieldOrder::Alphabetical),
+            ..Default::default()
+        };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        assert_eq!(member_names(&program, "First"), ["owner", "amount", "data", "flag"]);
+        assert_eq!(member_names(&program, "First"), member_names(&program, "Second"));
+        // The records were declared in different orders.
+        assert_eq!(member_names(&frontend.program, "Second"), ["data", "amount", "flag", "owner"]);
+    })
+}
+
+#[test]
+fn test_leading_and_trailing_layout() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        amount: u64,
+        data: u8,
+        owner: address,
+        flag: bool,
+    }
+
+    transition main(a: u64) -> Token {
+        let token: Token = Token { data: 1u8, flag: true, owner: self.caller, amount: a };
+        return token;
+    }
+}",
+        );
+        let layout = RecordLayout::new(vec![sym::owner]).with_trailing(vec![Symbol::intern("data")]);
+        let program = frontend
+            .assigner(SsaOptions { record_layout: layout, ..Default::default() })
+            .consume_program(frontend.program.clone());
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "flag", "data"]);
+        assert!(frontend.errors().is_empty());
+
+        // The initializer of the record is arranged in the same order.
+        let initializers: Vec<String> = function(&program, "main")
+            .block
+            .statements
+            .iter()
+            .find_map(|statement| match statement {
+                Statement::Assign(assign) => match &assign.value {
+                    Expression::Struct(init) => {
+                        Some(init.members.iter().map(|member| member.identifier.to_string()).collect())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .expect("`main` should initialize a `Token`.");
+        assert_eq!(initializers, ["owner", "amount", "flag", "data"]);
+    })
+}
+
+#[test]
+fn test_layout_is_resolved_per_program_scope() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let other = Frontend::new(&PROGRAM.replace("test.aleo", "other.aleo"));
+        let mut program = frontend.program.clone();
+        program.imports.insert(Symbol::intern("other"), (other.program.clone(), Default::default()));
+
+        // `other` targets the `[gates, owner]` layout, while `test` targets the standard layout.
+        let resolver = LayoutResolver::new(|program_id| match program_id.name.name.to_string().as_str() {
+            "other" => RecordLayout::new(vec![sym::gates, sym::owner]),
+            _ => RecordLayout::default(),
+        });
+        let options = SsaOptions { layout_resolver: Some(resolver), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(program);
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "data", "gates", "flag"]);
+        let (import, _) = &program.imports[&Symbol::intern("other")];
+        assert_eq!(member_names(import, "Token"), ["gates", "owner", "amount", "data", "flag"]);
+    })
+}
+
+/// Returns the options that lowercase the names of the fields of records.
+fn lowercase_fields() -> SsaOptions {
+    SsaOptions {
+        field_name_transform: Some(RenameTransform::new(|symbol| Symbol::intern(&symbol.to_string().to_lowercase()))),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_field_name_transform_lowercases_fields() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        Amount: u64,
+        owner: address,
+        Data: u8,
+    }
+
+    transition mint(a: u64, b: address, d: u8) -> Token {
+        return Token { Amount: a, owner: b, Data: d };
+    }
+
+    transition amount(token: Token) -> u64 {
+        return token.Amount;
+    }
+}",
+        );
+        let program = frontend.assigner(lowercase_fields()).consume_program(frontend.program.clone());
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "data"]);
+        // The initializers and accesses of the fields are renamed along with the members.
+        let mint = function(&program, "mint").block.to_string();
+        assert!(mint.contains("owner: b, amount: a, data: d"), "{mint}");
+        let amount = function(&program, "amount").block.to_string();
+        assert!(amount.contains("token.amount"), "{amount}");
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_transformed_field_names_are_arranged_in_initializers() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        B: u8,
+        a: u8,
+    }
+
+    transition mint(b: address, x: u8, y: u8) -> Token {
+        return Token { owner: b, B: x, a: y };
+    }
+}",
+        );
+        // The transformed names are arranged, so `b` follows `a`, although `B` precedes `a` in the source.
+        let options = SsaOptions {
+            record_layout: RecordLayout::default().with_remaining_order(FieldOrder::Alphabetical),
+            ..lowercase_fields()
+        };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "a", "b"]);
+        let mint = function(&program, "mint").block.to_string();
+        assert!(mint.contains("owner: b, a: y, b: x"), "{mint}");
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_colliding_transformed_field_names_are_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        Amount: u64,
+        amount: u64,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let program = frontend.assigner(lowercase_fields()).consume_program(frontend.program.clone());
+
+        // The fields of the record are left unchanged.
+        assert_eq!(member_names(&program, "Token"), ["owner", "Amount", "amount"]);
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("`Amount` and `amount`"), "{}", errors[0]);
+    })
+}
+
+/// Returns the members of the record `Token` with the given `fields`, after the pass with the `[owner, gates]` layout.
+fn owner_gates_members(fields: &str) -> Vec<String> {
+    let frontend = Frontend::new(&format!(
+        "
+program test.aleo {{
+    record Token {{
+        {fields}
+    }}
+
+    transition main(a: u8) -> u8 {{
+        return a;
+    }}
+}}"
+    ));
+    let options = SsaOptions { record_layout: RecordLayout::new(vec![sym::owner, sym::gates]), ..Default::default() };
+    let program = frontend.assigner(options).consume_program(frontend.program.clone());
+    assert!(frontend.errors().is_empty(), "{:?}", frontend.errors());
+    member_names(&program, "Token")
+}
+
+#[test]
+fn test_record_with_only_reserved_fields() {
+    create_session_if_not_set_then(|_| {
+        // There are no remaining fields to place after the reserved fields.
+        assert_eq!(owner_gates_members("gates: u64, owner: address,"), ["owner", "gates"]);
+        assert_eq!(owner_gates_members("owner: address, gates: u64,"), ["owner", "gates"]);
+    })
+}
+
+#[test]
+fn test_record_with_reserved_fields_and_one_other_field() {
+    create_session_if_not_set_then(|_| {
+        assert_eq!(owner_gates_members("gates: u64, amount: u64, owner: address,"), ["owner", "gates", "amount"]);
+        assert_eq!(owner_gates_members("amount: u64, owner: address, gates: u64,"), ["owner", "gates", "amount"]);
+    })
+}
+
+#[test]
+fn test_strict_records_reject_only_non_canonical_records() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Canonical {
+        owner: address,
+        amount: u64,
+    }
+
+    record Reordered {
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let program = frontend
+            .assigner(SsaOptions { strict_records: true, ..Default::default() })
+            .consume_program(frontend.program.clone());
+
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("The fields of the record `Reordered` are not in canonical order."));
+        assert!(errors[0].to_string().contains("`owner, amount`"), "{}", errors[0]);
+        // The pass still produces the canonical order.
+        assert_eq!(member_names(&program, "Reordered"), ["owner", "amount"]);
+    })
+}
+
+#[test]
+fn test_same_named_record_and_struct_in_different_scopes() {
+    create_session_if_not_set_then(|_| {
+        let source = |program: &str, kind: &str| {
+            format!(
+                "
+program {program}.aleo {{
+    {kind} Token {{
+        amount: u64,
+        owner: address,
+    }}
+
+    transition main(a: u8) -> u8 {{
+        return a;
+    }}
+}}"
+            )
+        };
+        // `test` declares `Token` as a struct, while its import `other` declares `Token` as a record.
+        let frontend = Frontend::new(&source("test", "struct"));
+        let other = Frontend::new(&source("other", "record"));
+        let mut program = frontend.program.clone();
+        program.imports.insert(Symbol::intern("other"), (other.program.clone(), Default::default()));
+
+        let program = frontend.assigner(SsaOptions::default()).consume_program(program);
+
+        // Each declaration is handled according to whether it is a record.
+        assert_eq!(member_names(&program, "Token"), ["amount", "owner"]);
+        let (import, _) = &program.imports[&Symbol::intern("other")];
+        assert_eq!(member_names(import, "Token"), ["owner", "amount"]);
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+const MINT: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+        data: u8,
+    }
+
+    transition mint(a: u64, b: address, d: u8) -> Token {
+        return Token { data: d, owner: b, amount: a };
+    }
+}";
+
+#[test]
+fn test_preserved_member_order_records_canonical_order() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(MINT);
+        let mut assigner = frontend.assigner(SsaOptions { preserve_member_order: true, ..Default::default() });
+        let program = assigner.consume_program(frontend.program.clone());
+
+        // The members and initializers are kept in source order.
+        assert_eq!(member_names(&program, "Token"), ["amount", "owner", "data"]);
+        let mint = function(&program, "mint").block.to_string();
+        assert!(mint.contains("amount: a, owner: b, data: d"), "{mint}");
+        // The canonical order is recorded separately.
+        let canonical: Vec<String> =
+            assigner.canonical_order(Symbol::intern("Token")).unwrap().iter().map(|name| name.to_string()).collect();
+        assert_eq!(canonical, ["owner", "amount", "data"]);
+        // The validator accepts the preserved order.
+        assert!(assigner.validate(&program).is_empty());
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_canonical_order_matches_output_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(MINT);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "data"]);
+        let mint = function(&program, "mint").block.to_string();
+        assert!(mint.contains("owner: b, amount: a, data: d"), "{mint}");
+        let canonical: Vec<String> =
+            assigner.canonical_order(Symbol::intern("Token")).unwrap().iter().map(|name| name.to_string()).collect();
+        assert_eq!(canonical, member_names(&program, "Token"));
+    })
+}
+
+/// Returns the warnings emitted when consuming a record declaring `fields` with the `[owner, gates]` layout.
+fn misplaced_field_warnings(fields: &str, warn: bool) -> Vec<String> {
+    let frontend = Frontend::new(&format!(
+        "
+program test.aleo {{
+    record Token {{
+        {fields}
+    }}
+
+    transition main(a: u8) -> u8 {{
+        return a;
+    }}
+}}"
+    ));
+    let options = SsaOptions {
+        record_layout: RecordLayout::new(vec![sym::owner, sym::gates]),
+        warn_misplaced_reserved_fields: warn,
+        ..Default::default()
+    };
+    frontend.assigner(options).consume_program(frontend.program.clone());
+    frontend.warnings().iter().map(|warning| warning.to_string()).collect()
+}
+
+#[test]
+fn test_misplaced_owner_field_is_reported_alone() {
+    create_session_if_not_set_then(|_| {
+        let warnings = misplaced_field_warnings("amount: u64, owner: address,", true);
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0]
+            .contains("The `owner` field of the record `Token` is declared at position 2, rather than position 1."));
+    })
+}
+
+#[test]
+fn test_misplaced_gates_field_is_reported_alone() {
+    create_session_if_not_set_then(|_| {
+        let warnings = misplaced_field_warnings("owner: address, amount: u64, gates: u64,", true);
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0]
+            .contains("The `gates` field of the record `Token` is declared at position 3, rather than position 2."));
+    })
+}
+
+#[test]
+fn test_misplaced_reserved_fields_are_not_reported_by_default() {
+    create_session_if_not_set_then(|_| {
+        assert!(misplaced_field_warnings("gates: u64, amount: u64, owner: address,", false).is_empty());
+        assert!(misplaced_field_warnings("owner: address, gates: u64, amount: u64,", true).is_empty());
+    })
+}
+
+#[test]
+fn test_struct_rewriter_runs_before_reordering() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        // Inject a `checksum` field at the beginning of each record.
+        let rewriter = StructRewriter::new(|mut struct_| {
+            if struct_.is_record {
+                struct_.members.insert(0, Member {
+                    mode: Mode::None,
+                    identifier: Identifier::new(Symbol::intern("checksum"), Default::default()),
+                    type_: Type::Integer(IntegerType::U64),
+                    span: Default::default(),
+                    id: Default::default(),
+                });
+            }
+            struct_
+        });
+        let options = SsaOptions { struct_rewriter: Some(rewriter), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // The injected field is ordered along with the declared fields, so `owner` is still hoisted.
+        assert_eq!(member_names(&program, "Token"), ["owner", "checksum", "amount", "data", "gates", "flag"]);
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_struct_rewriter_applies_to_initializers() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        amount: u64,
+        data: u8,
+        owner: address,
+    }
+
+    transition main(a: u64) -> Token {
+        return Token { amount: a, data: 1u8, owner: self.caller };
+    }
+}",
+        );
+        // Returns the names of the members initialized by the last statement of `main` before its return.
+        let initialized = |program: &Program| match &function(program, "main").block.statements.iter().rev().nth(1) {
+            Some(Statement::Assign(assign)) => match &assign.value {
+                Expression::Struct(init) => {
+                    init.members.iter().map(|member| member.identifier.name.to_string()).collect::<Vec<_>>()
+                }
+                value => panic!("Expected a struct initializer, found `{value}`."),
+            },
+            statement => panic!("Expected an assignment, found `{statement:?}`."),
+        };
+
+        // Removing `data` removes its initializer, and the remaining initializers follow the rewritten record.
+        let rewriter = StructRewriter::new(|mut struct_| {
+            struct_.members.retain(|member| member.name() != Symbol::intern("data"));
+            struct_
+        });
+        let options = SsaOptions { struct_rewriter: Some(rewriter), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount"]);
+        assert_eq!(initialized(&program), ["owner", "amount"]);
+        assert!(frontend.errors().is_empty());
+
+        // A member added by the rewriter is initialized by its default.
+        let rewriter = StructRewriter::new(|mut struct_| {
+            struct_.members.push(Member {
+                mode: Mode::None,
+                identifier: Identifier::new(Symbol::intern("checksum"), Default::default()),
+                type_: Type::Integer(IntegerType::U64),
+                span: Default::default(),
+                id: Default::default(),
+            });
+            struct_
+        });
+        let zero = |_: &Member| {
+            Some(Expression::Literal(Literal::Integer(
+                IntegerType::U64,
+                "0".into(),
+                Default::default(),
+                Default::default(),
+            )))
+        };
+        let options = SsaOptions { struct_rewriter: Some(rewriter.clone().with_default(zero)), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "data", "checksum"]);
+        assert_eq!(initialized(&program), ["owner", "amount", "data", "checksum"]);
+        let main = function(&program, "main").block.to_string();
+        assert!(main.contains("= 0u64;"), "{main}");
+        assert!(frontend.errors().is_empty());
+
+        // Without a default, the member added by the rewriter cannot be initialized, so the initializer is reported.
+        let options = SsaOptions { struct_rewriter: Some(rewriter), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount", "data", "checksum"]);
+        assert_eq!(initialized(&program), ["owner", "amount", "data"]);
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0]
+                .to_string()
+                .contains("The member `checksum` added to `Token` by the struct rewriter is not initialized."),
+            "{}",
+            errors[0]
+        );
+    })
+}
+
+#[test]
+fn test_field_name_transform_applies_to_rewritten_records() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        Amount: u64,
+        amount: u64,
+    }
+
+    transition main(token: Token) -> u64 {
+        return token.Amount;
+    }
+}",
+        );
+        // The transformed names of the source collide, but those of the rewritten record do not.
+        let rewriter = StructRewriter::new(|mut struct_| {
+            struct_.members.retain(|member| member.name() != Symbol::intern("amount"));
+            struct_
+        });
+        let options = SsaOptions { struct_rewriter: Some(rewriter), ..lowercase_fields() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // The access is renamed along with the member of the rewritten record.
+        assert_eq!(member_names(&program, "Token"), ["owner", "amount"]);
+        let main = function(&program, "main").block.to_string();
+        assert!(main.contains("token.amount"), "{main}");
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_record_layouts_are_diffed_against_a_baseline() {
+    create_session_if_not_set_then(|_| {
+        let baseline = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+        data: u8,
+    }
+
+    record Ticket {
+        owner: address,
+        id: u64,
+    }
+
+    record Vault {
+        balance: u64,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let current = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        data: u8,
+        owner: address,
+        amount: u64,
+        memo: u8,
+    }
+
+    record Ticket {
+        owner: address,
+    }
+
+    record Vault {
+        owner: address,
+        balance: u64,
+    }
+
+    record Badge {
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let layouts = |frontend: &Frontend| {
+            record_layouts(&frontend.assigner(SsaOptions::default()).consume_records(frontend.program.clone()))
+        };
+
+        // `Vault` has the same canonical layout in both, so it contributes nothing.
+        let changes: Vec<String> = diff_record_layouts(&layouts(&baseline), &layouts(&current))
+            .iter()
+            .map(|change| change.to_string())
+            .collect();
+        assert_eq!(changes, [
+            "test.aleo/Token: field `memo` added",
+            "test.aleo/Token: fields reordered from [owner, amount, data] to [owner, data, amount]",
+            "test.aleo/Ticket: field `id` removed",
+            "test.aleo/Badge: added",
+        ]);
+        assert!(diff_record_layouts(&layouts(&baseline), &layouts(&baseline)).is_empty());
+    })
+}
+
+/// Returns the warnings and the output members of `Token` declaring `fields`, consumed with `options.expect_normalized_records`.
+fn normalization_warnings(fields: &str) -> (Vec<String>, Vec<String>) {
+    let frontend = Frontend::new(&format!(
+        "
+program test.aleo {{
+    record Token {{
+        {fields}
+    }}
+
+    transition main(a: u8) -> u8 {{
+        return a;
+    }}
+}}"
+    ));
+    let options = SsaOptions { expect_normalized_records: true, ..Default::default() };
+    let program = frontend.assigner(options).consume_program(frontend.program.clone());
+    assert!(frontend.errors().is_empty());
+    (frontend.warnings().iter().map(|warning| warning.to_string()).collect(), member_names(&program, "Token"))
+}
+
+#[test]
+fn test_normalized_records_pass_the_pipeline_check() {
+    create_session_if_not_set_then(|_| {
+        let (warnings, members) = normalization_warnings("owner: address, amount: u64,");
+
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(members, ["owner", "amount"]);
+    })
+}
+
+#[test]
+fn test_records_needing_reordering_fail_the_pipeline_check() {
+    create_session_if_not_set_then(|_| {
+        let (warnings, members) = normalization_warnings("amount: u64, owner: address,");
+
+        // The record is still reordered.
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("expected to be normalized by a prior pass"), "{}", warnings[0]);
+        assert_eq!(members, ["owner", "amount"]);
+    })
+}
+
+/// Returns the output members of `Token` declaring `fields` with the migration layout, along with the warnings.
+fn migrated_members(fields: &str) -> (Vec<String>, Vec<String>) {
+    let frontend = Frontend::new(&format!(
+        "
+program test.aleo {{
+    record Token {{
+        {fields}
+    }}
+
+    transition main(a: u8) -> u8 {{
+        return a;
+    }}
+}}"
+    ));
+    let options = SsaOptions {
+        record_layout: RecordLayout::migration(),
+        note_absent_optional_fields: true,
+        ..Default::default()
+    };
+    let program = frontend.assigner(options).consume_program(frontend.program.clone());
+    assert!(frontend.errors().is_empty());
+    (member_names(&program, "Token"), frontend.warnings().iter().map(|warning| warning.to_string()).collect())
+}
+
+#[test]
+fn test_migration_layout_hoists_gates_when_present() {
+    create_session_if_not_set_then(|_| {
+        let (members, warnings) = migrated_members("amount: u64, gates: u64, owner: address,");
+
+        assert_eq!(members, ["owner", "gates", "amount"]);
+        assert!(warnings.is_empty(), "{warnings:?}");
+    })
+}
+
+#[test]
+fn test_migration_layout_accepts_records_without_gates() {
+    create_session_if_not_set_then(|_| {
+        let (members, warnings) = migrated_members("amount: u64, owner: address,");
+
+        // The absence of `gates` is only noted.
+        assert_eq!(members, ["owner", "amount"]);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("does not declare the optional field `gates`"), "{}", warnings[0]);
+    })
+}
+
+#[test]
+fn test_migration_layout_treats_records_without_gates_as_canonical() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let options =
+            SsaOptions { record_layout: RecordLayout::migration(), strict_records: true, ..Default::default() };
+        let (_, summary) = frontend.assigner(options).consume_program_with_summary(frontend.program.clone());
+
+        assert!(frontend.errors().is_empty());
+        assert_eq!(summary.records_reordered, 0);
+    })
+}
+
+#[test]
+fn test_struct_type_parameters_are_rejected_by_the_parser() {
+    create_session_if_not_set_then(|_| {
+        // Structs have no type parameters to scope, so a parameterized struct never reaches the pass.
+        assert!(!parses(
+            "
+program test.aleo {
+    struct Pair<T> {
+        first: T,
+        second: T,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}"
+        ));
+    })
+}
+
+#[test]
+fn test_reserved_field_positions_are_reported_before_reordering() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    record Ticket {
+        owner: address,
+        gates: u64,
+        id: u8,
+    }
+
+    record Badge {
+        id: u8,
+        gates: u64,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let (program, positions) =
+            frontend.assigner(SsaOptions::default()).consume_program_with_reserved_positions(frontend.program.clone());
+
+        // Every record is listed, including the canonical `Ticket`, with the positions in the source.
+        let positions: Vec<String> = positions.iter().map(|positions| positions.to_string()).collect();
+        assert_eq!(positions, [
+            "test.aleo/Token: `owner` at position 2, `gates` absent",
+            "test.aleo/Ticket: `owner` at position 1, `gates` at position 2",
+            "test.aleo/Badge: `owner` at position 3, `gates` at position 2",
+        ]);
+        assert_eq!(member_names(&program, "Badge"), ["owner", "id", "gates"]);
+    })
+}
+
+#[test]
+fn test_abort_on_reordering_stops_at_the_first_reordered_record() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Ticket {
+        owner: address,
+        id: u8,
+    }
+
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    record Badge {
+        id: u8,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        return b;
+    }
+}",
+        );
+        let options = SsaOptions { abort_on_reordering: true, ..Default::default() };
+        let mut assigner = frontend.assigner(options);
+        let program = assigner.consume_program(frontend.program.clone());
+
+        // The canonical `Ticket` is passed over, and the pass stops at `Token`.
+        let reordering = assigner.first_reordering().expect("The pass should stop at `Token`.");
+        assert_eq!(reordering.record.to_string(), "test.aleo/Token");
+        assert_eq!(reordering.order, [Symbol::intern("owner"), Symbol::intern("amount")]);
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("Token"), "{}", errors[0]);
+
+        // The remainder of the program is left unchanged.
+        assert_eq!(member_names(&program, "Token"), ["amount", "owner"]);
+        assert_eq!(member_names(&program, "Badge"), ["id", "owner"]);
+        assert!(!function(&program, "main").block.to_string().contains('$'));
+    })
+}
+
+#[test]
+fn test_member_indices_map_source_to_output_positions() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Ticket {
+        owner: address,
+        id: u8,
+    }
+
+    record Badge {
+        id: u8,
+        gates: u64,
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        // `owner` moves to the front of `Badge`, shifting `id` and `gates` back by one.
+        assert_eq!(member_names(&program, "Badge"), ["owner", "id", "gates", "amount"]);
+        assert_eq!(assigner.member_indices(Symbol::intern("Badge")), Some(&[1, 2, 0, 3][..]));
+        // The canonical `Ticket` is left unchanged.
+        assert_eq!(assigner.member_indices(Symbol::intern("Ticket")), Some(&[0, 1][..]));
+
+        // The members are left in source order if `options.preserve_member_order` is set.
+        let mut assigner = frontend.assigner(SsaOptions { preserve_member_order: true, ..Default::default() });
+        assigner.consume_program(frontend.program.clone());
+        assert_eq!(assigner.member_indices(Symbol::intern("Badge")), Some(&[0, 1, 2, 3][..]));
+    })
+}
+
+#[test]
+fn test_field_types_follow_the_output_order() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Ticket {
+        owner: address,
+        id: u8,
+    }
+
+    record Voucher {
+        ticket: Ticket,
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+        // Returns the names and types of the fields of `record`.
+        let field_types = |record: &str| {
+            assigner
+                .field_types(Symbol::intern(record))
+                .unwrap()
+                .iter()
+                .map(|(name, type_)| format!("{name}: {type_}"))
+                .collect::<Vec<_>>()
+        };
+
+        // `owner` moves to the front of `Voucher`, whose `ticket` field depends on `Ticket`.
+        assert_eq!(field_types("Voucher"), ["owner: address", "ticket: Ticket", "amount: u64"]);
+        assert_eq!(field_types("Ticket"), ["owner: address", "id: u8"]);
+        let (_, ticket) = &assigner.field_types(Symbol::intern("Voucher")).unwrap()[1];
+        assert!(matches!(ticket, Type::Identifier(identifier) if identifier.name == Symbol::intern("Ticket")));
+        // Only consumed records are listed.
+        assert_eq!(assigner.field_types(Symbol::intern("main")), None);
+    })
+}
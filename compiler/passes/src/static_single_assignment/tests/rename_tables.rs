@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, Frontend};
+use crate::{RenameTable, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        return b then finalize(a);
+    }
+
+    finalize main(a: u8) {
+        let c: u8 = a;
+        assert_eq(c, a);
+    }
+}";
+
+#[test]
+fn test_rename_tables_are_retained() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions { retain_rename_tables: true, ..Default::default() });
+        assigner.consume_program(frontend.program.clone());
+
+        let main = Symbol::intern("main");
+        let table = assigner.rename_table(main).expect("The table of `main` should be retained.");
+        assert_eq!(table.lookup(Symbol::intern("a")), Some(&Symbol::intern("a")));
+        assert_eq!(table.lookup(Symbol::intern("b")).map(|name| name.to_string()), Some("b$0".to_string()));
+        assert!(table.lookup(Symbol::intern("c")).is_none());
+
+        // The finalize block has a separate table.
+        let table = assigner.finalize_rename_table(main).expect("The table of `finalize main` should be retained.");
+        assert_eq!(table.lookup(Symbol::intern("c")).map(|name| name.to_string()), Some("c$1".to_string()));
+        assert!(table.lookup(Symbol::intern("b")).is_none());
+    })
+}
+
+#[test]
+fn test_rename_tables_are_not_retained_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        assert!(assigner.rename_table(Symbol::intern("main")).is_none());
+        assert!(assigner.finalize_rename_table(Symbol::intern("main")).is_none());
+    })
+}
+
+#[test]
+fn test_rename_table_threshold_does_not_change_output() {
+    create_session_if_not_set_then(|_| {
+        let run = |rename_table_threshold| {
+            Frontend::new(PROGRAM).output(SsaOptions { rename_table_threshold, ..Default::default() })
+        };
+
+        // Thresholds of zero and one promote every non-empty scope to a map.
+        let expected = run(None);
+        assert_eq!(run(Some(0)), expected);
+        assert_eq!(run(Some(1)), expected);
+    })
+}
+
+#[test]
+fn test_rename_tables_with_different_thresholds_are_equal() {
+    create_session_if_not_set_then(|_| {
+        let (a, b) = (Symbol::intern("a"), Symbol::intern("b"));
+        let fill = |threshold| {
+            let mut table = RenameTable::with_threshold(None, threshold);
+            table.update(a, Symbol::intern("a$0"), 0);
+            table.update(b, Symbol::intern("b$1"), 1);
+            table
+        };
+
+        // The entries of the first table are promoted to a map, and those of the second are stored inline.
+        assert_eq!(fill(0), fill(RenameTable::DEFAULT_THRESHOLD));
+        assert_ne!(fill(0), RenameTable::with_threshold(None, 0));
+    })
+}
+
+#[test]
+fn test_rename_table_limit_stops_the_pass() {
+    create_session_if_not_set_then(|_| {
+        let source = "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        let c: u8 = b;
+        let d: u8 = c;
+        let e: u8 = d;
+        return e;
+    }
+
+    function helper(a: u8) -> u8 {
+        let b: u8 = a;
+        return b;
+    }
+}";
+        let frontend = Frontend::new(source);
+        let options = SsaOptions { max_rename_entries: Some(4), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // `main` exceeds the limit, so it is reported, and `helper` is left unchanged.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("The function `main` exceeds the limit of 4 entries"), "{}", errors[0]);
+        assert!(!function(&program, "helper").block.to_string().contains('$'));
+
+        // The same program is within a generous limit.
+        let frontend = Frontend::new(source);
+        let options = SsaOptions { max_rename_entries: Some(100), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+        assert!(frontend.errors().is_empty());
+        assert!(function(&program, "helper").block.to_string().contains("b$"));
+    })
+}
@@ -0,0 +1,258 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{assigned_names, function, Frontend};
+use crate::{CollisionChecker, RenameHook, RenameTransform, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+use indexmap::IndexSet;
+use std::sync::{Arc, Mutex};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a + b;
+        if c == 0u8 {
+            c = 1u8;
+        } else {
+            c = c * 2u8;
+        }
+        return c;
+    }
+}";
+
+#[test]
+fn test_rename_transform_prefixes_new_names() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions {
+            rename_transform: Some(RenameTransform::new(|symbol| Symbol::intern(&format!("test::{symbol}")))),
+            ..Default::default()
+        };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        let names = assigned_names(&function(&program, "main").block);
+        assert!(!names.is_empty());
+        // Each new name carries the prefix.
+        assert!(names.iter().all(|name| name.starts_with("test::")), "{names:?}");
+        // The new names remain unique.
+        assert_eq!(names.iter().collect::<IndexSet<_>>().len(), names.len(), "{names:?}");
+    })
+}
+
+#[test]
+fn test_versions_of_the_same_original() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        // Each version of `c` shares the original `c`.
+        let names = assigned_names(&function(&program, "main").block);
+        let versions: Vec<Symbol> =
+            names.iter().filter(|name| name.starts_with("c$")).map(|name| Symbol::intern(name)).collect();
+        assert!(versions.len() > 1, "{names:?}");
+        for version in &versions {
+            assert_eq!(assigner.original_name(*version), Some(Symbol::intern("c")));
+            assert!(assigner.is_same_original(versions[0], *version));
+        }
+
+        // The versions of different originals, including temporaries, are distinct.
+        let temporary = names.iter().find(|name| name.starts_with("$var$")).map(|name| Symbol::intern(name)).unwrap();
+        assert!(!assigner.is_same_original(versions[0], temporary));
+        assert!(!assigner.is_same_original(versions[0], Symbol::intern("a")));
+        assert!(assigner.is_same_original(Symbol::intern("a"), Symbol::intern("a")));
+    })
+}
+
+#[test]
+fn test_original_names_of_inputs_and_body_variables() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        // The inputs keep their names, and resolve to themselves.
+        for input in ["a", "b"] {
+            assert_eq!(assigner.original_name(Symbol::intern(input)), Some(Symbol::intern(input)));
+        }
+
+        // Each renamed body variable resolves to its source name, while temporaries have none.
+        for name in assigned_names(&function(&program, "main").block) {
+            let original = assigner.original_name(Symbol::intern(&name));
+            match name.starts_with("$var$") {
+                true => assert_eq!(original, None, "{name}"),
+                false => assert_eq!(original, Some(Symbol::intern("c")), "{name}"),
+            }
+        }
+        assert_eq!(assigner.original_name(Symbol::intern("c")), None);
+    })
+}
+
+/// Returns the options with a rename hook that keeps the original name of `pinned`.
+fn pinning(pinned: &'static str) -> SsaOptions {
+    let hook = RenameHook::new(move |original, _| (original == Symbol::intern(pinned)).then_some(original));
+    SsaOptions { rename_hook: Some(hook), ..Default::default() }
+}
+
+#[test]
+fn test_rename_hook_pins_a_variable() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        let total: u8 = a + b;
+        let c: u8 = total;
+        c = c * 2u8;
+        return c;
+    }
+}",
+        );
+        let program = frontend.assigner(pinning("total")).consume_program(frontend.program.clone());
+
+        // `total` keeps its name, while `c` is renamed as usual.
+        let names = assigned_names(&function(&program, "main").block);
+        assert!(names.contains(&"total".to_string()), "{names:?}");
+        assert_eq!(names.iter().filter(|name| name.starts_with("c$")).count(), 2, "{names:?}");
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_rename_hook_choices_must_be_unique() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let program = frontend.assigner(pinning("c")).consume_program(frontend.program.clone());
+
+        // `c` is assigned more than once, so only its first assignment keeps the name.
+        let names = assigned_names(&function(&program, "main").block);
+        assert_eq!(names.iter().filter(|name| *name == "c").count(), 1, "{names:?}");
+        let errors = frontend.errors();
+        assert!(!errors.is_empty());
+        assert!(errors.iter().all(|error| error.to_string().contains("The name `c` chosen for `c`")), "{errors:?}");
+    })
+}
+
+#[test]
+fn test_collision_checker_forces_a_retry() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        // Reject each new name with an even suffix, recording the rejected names.
+        let rejected = Arc::new(Mutex::new(Vec::new()));
+        let checker = CollisionChecker::new({
+            let rejected = rejected.clone();
+            move |proposed, _| {
+                let accepted = !proposed.to_string().ends_with(['0', '2', '4', '6', '8']);
+                if !accepted {
+                    rejected.lock().unwrap().push(proposed.to_string());
+                }
+                accepted
+            }
+        });
+        let options = SsaOptions { collision_checker: Some(checker), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // The rejected names are replaced by the next names proposed by the pass.
+        let rejected = core::mem::take(&mut *rejected.lock().unwrap());
+        assert!(!rejected.is_empty());
+        let names = assigned_names(&function(&program, "main").block);
+        assert!(rejected.iter().all(|name| !names.contains(name)), "{rejected:?} {names:?}");
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_collision_checker_rejecting_every_name_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions { collision_checker: Some(CollisionChecker::new(|_, _| false)), ..Default::default() };
+        frontend.assigner(options).consume_program(frontend.program.clone());
+
+        let errors = frontend.errors();
+        assert!(!errors.is_empty());
+        assert!(errors[0].to_string().contains("rejected all 8 new names proposed for `c`"), "{}", errors[0]);
+    })
+}
+
+#[test]
+fn test_default_collision_checker_rejects_names_in_scope() {
+    create_session_if_not_set_then(|_| {
+        let checker = CollisionChecker::default();
+        let scope = [(Symbol::intern("a"), Symbol::intern("a$1"))].into_iter().collect();
+        assert!(!checker.accepts(Symbol::intern("a$1"), &scope));
+        assert!(checker.accepts(Symbol::intern("a$2"), &scope));
+    })
+}
+
+const FIELD_ACCESS: &str = "
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(r: Token, flag: bool) -> (address, u64) {
+        let owner: address = r.owner;
+        let s: Token = r;
+        if flag {
+            s = Token { owner: owner, amount: r.amount + 1u64 };
+        }
+        return (s.owner, s.amount);
+    }
+}";
+
+/// Consumes `FIELD_ACCESS` with `options`, returning the block of `main` along with the names it assigns.
+fn field_accesses(options: SsaOptions) -> (String, Vec<String>) {
+    let frontend = Frontend::new(FIELD_ACCESS);
+    let program = frontend.assigner(options).consume_program(frontend.program.clone());
+    assert!(frontend.errors().is_empty());
+    let block = &function(&program, "main").block;
+    (block.to_string(), assigned_names(block))
+}
+
+/// Checks that each field access renames its base, but not its field, where `prefix` begins each new name.
+fn check_field_accesses(block: &str, names: &[String], prefix: &str) {
+    // The names of the fields are untouched, even though the variable `owner` shares the name of a field.
+    assert!(!block.contains(".owner$") && !block.contains(".amount$") && !block.contains(".t_"), "{block}");
+    // The input `r` keeps its name.
+    assert!(block.contains("r.owner") && block.contains("r.amount"), "{block}");
+    assert!(names.iter().any(|name| name.starts_with(&format!("{prefix}owner$"))), "{names:?}");
+    // The accesses of `s` use the name of the phi function, the last name assigned to `s`.
+    let s = names.iter().rfind(|name| name.starts_with(&format!("{prefix}s$"))).expect("`s` should be renamed.");
+    assert!(block.contains(&format!("{s}.owner")) && block.contains(&format!("{s}.amount")), "{block}");
+}
+
+#[test]
+fn test_field_access_renames_only_the_base() {
+    create_session_if_not_set_then(|_| {
+        let (block, names) = field_accesses(SsaOptions::default());
+        check_field_accesses(&block, &names, "");
+    })
+}
+
+#[test]
+fn test_rename_transform_does_not_apply_to_accessed_fields() {
+    create_session_if_not_set_then(|_| {
+        let (block, names) = field_accesses(SsaOptions {
+            rename_transform: Some(RenameTransform::new(|symbol| Symbol::intern(&format!("t_{symbol}")))),
+            ..Default::default()
+        });
+        check_field_accesses(&block, &names, "t_");
+    })
+}
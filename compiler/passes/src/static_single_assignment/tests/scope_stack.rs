@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, Frontend};
+use crate::{ScopeStack, SsaOptions, StaticSingleAssigner};
+
+use leo_ast::{NodeID, ProgramConsumer};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+/// A trivial `ScopeStack` that stores each scope as a list of renamings, searched linearly from the latest renaming.
+#[derive(Clone, Debug)]
+struct ListScopes {
+    /// The renamings of each scope, from the outermost scope inwards.
+    scopes: Vec<Vec<(Symbol, Symbol, NodeID)>>,
+}
+
+impl Default for ListScopes {
+    fn default() -> Self {
+        Self { scopes: vec![Vec::new()] }
+    }
+}
+
+impl ListScopes {
+    /// Returns the renamings of all scopes, latest first.
+    fn renamings(&self) -> impl Iterator<Item = &(Symbol, Symbol, NodeID)> {
+        self.scopes.iter().rev().flat_map(|scope| scope.iter().rev())
+    }
+}
+
+impl ScopeStack for ListScopes {
+    fn push(&mut self) {
+        self.scopes.push(Vec::new());
+    }
+
+    fn pop(&mut self) -> Self {
+        let popped = self.clone();
+        self.scopes.pop();
+        if self.scopes.is_empty() {
+            self.scopes.push(Vec::new());
+        }
+        popped
+    }
+
+    fn update(&mut self, symbol: Symbol, new_symbol: Symbol, id: NodeID) {
+        self.scopes.last_mut().unwrap().push((symbol, new_symbol, id));
+    }
+
+    fn lookup(&self, symbol: Symbol) -> Option<&Symbol> {
+        self.renamings().find(|(name, _, _)| *name == symbol).map(|(_, new_name, _)| new_name)
+    }
+
+    fn lookup_id(&self, symbol: &Symbol) -> Option<&NodeID> {
+        self.renamings().find(|(_, new_name, _)| new_name == symbol).map(|(_, _, id)| id)
+    }
+
+    fn local_names(&self) -> Vec<Symbol> {
+        let mut names = Vec::new();
+        for (name, _, _) in self.scopes.last().unwrap() {
+            if !names.contains(name) {
+                names.push(*name);
+            }
+        }
+        names
+    }
+
+    fn into_innermost(mut self) -> Self {
+        Self { scopes: vec![self.scopes.pop().unwrap_or_default()] }
+    }
+}
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(flag: bool, a: u8) -> u8 {
+        let b: u8 = a;
+        if flag {
+            b = a + 1u8;
+        } else {
+            if a == 0u8 {
+                b = 2u8;
+            }
+        }
+        return b + a;
+    }
+}";
+
+#[test]
+fn test_alternative_scope_stack_produces_same_output() {
+    create_session_if_not_set_then(|_| {
+        let expected = {
+            let frontend = Frontend::new(PROGRAM);
+            let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+            function(&program, "main").block.to_string()
+        };
+
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = StaticSingleAssigner::with_scope_stack(
+            &frontend.node_builder,
+            &frontend.symbol_table,
+            &frontend.type_table,
+            &frontend.assigner,
+            &frontend.handler,
+            SsaOptions { retain_rename_tables: true, ..Default::default() },
+            ListScopes::default(),
+        );
+        let program = assigner.consume_program(frontend.program.clone());
+
+        assert_eq!(function(&program, "main").block.to_string(), expected);
+        assert!(frontend.errors().is_empty());
+        // The retained scope is the outermost scope of the function, holding the final name of `b`.
+        let table = assigner.rename_table(Symbol::intern("main")).unwrap();
+        let b = table.lookup(Symbol::intern("b")).expect("The final name of `b` should be retained.");
+        assert!(b.to_string().starts_with("b$") && expected.contains(&b.to_string()), "{b}");
+        assert_eq!(table.scopes.len(), 1);
+    })
+}
@@ -0,0 +1,380 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{assigned_names, function, parses, Frontend};
+use crate::{Pass, SsaOptions, StaticSingleAssigner, TypeTable, Unroller};
+
+use leo_ast::{Ast, Expression, NodeID, Program, ProgramConsumer, Statement};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+use indexmap::IndexSet;
+
+#[test]
+fn test_nested_functions_are_rejected_by_the_parser() {
+    create_session_if_not_set_then(|_| {
+        assert!(!parses(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        function inner(a: u8) -> u8 {
+            return a;
+        }
+        return a;
+    }
+}"
+        ));
+    })
+}
+
+#[test]
+fn test_bodiless_functions_are_rejected_by_the_parser() {
+    create_session_if_not_set_then(|_| {
+        assert!(!parses(
+            "
+program test.aleo {
+    function external(a: u8) -> u8;
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}"
+        ));
+    })
+}
+
+#[test]
+fn test_const_generic_functions_are_rejected_by_the_parser() {
+    create_session_if_not_set_then(|_| {
+        assert!(!parses(
+            "
+program test.aleo {
+    function scale::[N: u32](a: u32) -> u32 {
+        return a * N;
+    }
+
+    transition main(a: u32) -> u32 {
+        return a;
+    }
+}"
+        ));
+    })
+}
+
+#[test]
+fn test_constant_inputs_resolve_like_inputs() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    function scale(constant n: u32, a: u32) -> u32 {
+        let b: u32 = a * n;
+        return b;
+    }
+
+    transition main(a: u32) -> u32 {
+        return a;
+    }
+}",
+        );
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        // The body refers to the constant input by its own name.
+        let block = program.program_scopes.values().next().unwrap().functions[0].1.block.to_string();
+        assert!(block.contains("a * n"), "{block}");
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_functions_do_not_share_scopes() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        return b;
+    }
+
+    function helper(c: u8) -> u8 {
+        let b: u8 = c;
+        return b;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        // The variables of `main` are not visible in `helper`.
+        let free_variables = assigner.free_variables(Symbol::intern("helper")).unwrap();
+        assert_eq!(*free_variables, [Symbol::intern("c")].into_iter().collect::<IndexSet<_>>());
+    })
+}
+
+#[test]
+fn test_constants_are_passed_through() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    const LIMIT: u8 = 10u8;
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        let consts = |program: &Program| program.program_scopes.values().next().unwrap().consts.clone();
+        assert_eq!(consts(&program).len(), 1);
+        assert_eq!(consts(&program), consts(&frontend.program));
+    })
+}
+
+#[test]
+fn test_use_before_definition_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = c;
+        let c: u8 = a;
+        return b + c;
+    }
+}",
+        );
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        // Only the use of `c` that precedes its definition is reported.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("The variable `c` is used before it is defined."), "{}", errors[0]);
+    })
+}
+
+#[test]
+fn test_untyped_expressions_are_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b * a;
+    }
+}",
+        );
+        // The pass is given an empty type table, as if type checking had not run.
+        let type_table = TypeTable::default();
+        let mut assigner = StaticSingleAssigner::new(
+            &frontend.node_builder,
+            &frontend.symbol_table,
+            &type_table,
+            &frontend.assigner,
+            &frontend.handler,
+            SsaOptions::default(),
+        );
+        assigner.consume_program(frontend.program.clone());
+
+        // Only the first untyped expression is reported.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("The expression has no type."), "{}", errors[0]);
+    })
+}
+
+#[test]
+fn test_uses_after_definition_are_not_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = b + 1u8;
+        }
+        return b;
+    }
+}",
+        );
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_block_exits_record_the_variables_in_scope() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            let d: u8 = b;
+            b = d;
+        }
+        let e: u8 = b;
+        return e;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions { record_block_exits: true, ..Default::default() });
+        let program = assigner.consume_program(frontend.program.clone());
+
+        let block = &function(&program, "main").block;
+        let then = block
+            .statements
+            .iter()
+            .find_map(|statement| match statement {
+                Statement::Conditional(conditional) => Some(conditional.then.id),
+                _ => None,
+            })
+            .unwrap();
+        let exit = |id: NodeID| -> Vec<(String, String)> {
+            assigner.block_exit(id).unwrap().iter().map(|(name, new)| (name.to_string(), new.to_string())).collect()
+        };
+        let pairs = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs.iter().map(|(name, new)| (name.to_string(), new.to_string())).collect()
+        };
+
+        // The branch sees its own definitions, and the function block sees the phi function of `b` but not `d`.
+        assert_eq!(exit(then), pairs(&[("a", "a"), ("c", "c"), ("b", "b$2"), ("d", "d$1")]));
+        assert_eq!(exit(block.id), pairs(&[("a", "a"), ("c", "c"), ("b", "b$3"), ("e", "e$5")]));
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_block_exits_are_not_recorded_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        assert!(assigner.block_exit(function(&program, "main").block.id).is_none());
+    })
+}
+
+#[test]
+fn test_record_methods_are_rejected_by_the_parser() {
+    create_session_if_not_set_then(|_| {
+        assert!(!parses(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+
+        function total(self) -> u64 {
+            return self.amount;
+        }
+    }
+
+    transition main(a: u8) -> u8 {
+        return a;
+    }
+}"
+        ));
+    })
+}
+
+#[test]
+fn test_self_as_a_value_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: address) -> bool {
+        let b: bool = self.caller == a;
+        assert_eq(self, a);
+        return b;
+    }
+}",
+        );
+        frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        // Only the bare reference is reported, since `self.caller` is not renamed.
+        let errors = frontend.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("`self` is referenced as a value"), "{}", errors[0]);
+    })
+}
+
+#[test]
+fn test_unrolled_loop_iterations_are_renamed_in_isolation() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a;
+        for i: u8 in 0u8..2u8 {
+            let c: u8 = b + i;
+            b = c;
+        }
+        return b;
+    }
+}",
+        );
+        // Loops are unrolled before this pass, from the type-checked state of the frontend.
+        let (ast, symbol_table) = Unroller::do_pass((
+            Ast::new(frontend.program.clone()),
+            &frontend.handler,
+            &frontend.node_builder,
+            frontend.symbol_table.clone(),
+            &frontend.type_table,
+        ))
+        .expect("The loop should unroll.");
+        let program = StaticSingleAssigner::new(
+            &frontend.node_builder,
+            &symbol_table,
+            &frontend.type_table,
+            &frontend.assigner,
+            &frontend.handler,
+            SsaOptions::default(),
+        )
+        .consume_program(ast.into_repr());
+
+        // Each iteration defines its own version of `c`, and assigns its own version of `b`.
+        let main = function(&program, "main");
+        let names = assigned_names(&main.block);
+        let versions: Vec<&String> = names.iter().filter(|name| name.starts_with("c$")).collect();
+        assert_eq!(versions.len(), 2, "{names:?}");
+        assert_eq!(names.iter().collect::<IndexSet<_>>().len(), names.len(), "{names:?}");
+        // The return refers to the version of `b` assigned by the last iteration.
+        let last = names.iter().rfind(|name| name.starts_with("b$")).expect("`b` should be assigned.");
+        match main.block.statements.last() {
+            Some(Statement::Return(return_)) => match &return_.expression {
+                Expression::Identifier(identifier) => assert_eq!(&identifier.name.to_string(), last),
+                expression => panic!("Expected the return of an identifier, found `{expression}`."),
+            },
+            statement => panic!("Expected a return statement, found `{statement:?}`."),
+        }
+        assert!(frontend.errors().is_empty());
+    })
+}
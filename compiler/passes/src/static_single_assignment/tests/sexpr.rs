@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use super::Frontend;
+use crate::{to_sexpr, SsaOptions};
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::create_session_if_not_set_then;
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = a;
+        }
+        return b;
+    }
+}";
+
+const EXPECTED: &str = "(program
+  (scope test.aleo
+    (transition main ((a u8) (c bool)) (u8)
+      (block
+        (assign b$0 a)
+        (if c
+          (block
+            (assign b$1 a)))
+        (assign $var$3 (ternary c b$1 b$0))
+        (assign b$2 $var$3)
+        (return b$2)))))";
+
+#[test]
+fn test_sexpr_snapshot() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        assert_eq!(to_sexpr(&program), EXPECTED);
+    })
+}
+
+#[test]
+fn test_sexpr_is_deterministic() {
+    create_session_if_not_set_then(|_| {
+        let first = Frontend::new(PROGRAM);
+        let first = first.assigner(SsaOptions::default()).consume_program(first.program.clone());
+        let second = Frontend::new(PROGRAM);
+        let second = second.assigner(SsaOptions::default()).consume_program(second.program.clone());
+
+        assert_eq!(to_sexpr(&first), to_sexpr(&second));
+    })
+}
+
+#[test]
+fn test_sexpr_lowers_accesses_and_assertions() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(token: Token, a: u64) -> u64 {
+        let b: u64 = token.amount + a;
+        assert_eq(b, a);
+        return b;
+    }
+}",
+        );
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        // The renamed variables in accesses and assertions are atoms of the S-expression.
+        let sexpr = to_sexpr(&program);
+        assert!(sexpr.contains("(member token amount)"), "{sexpr}");
+        assert!(sexpr.contains("(assert-eq b$2 a)"), "{sexpr}");
+        assert!(frontend.errors().is_empty());
+    })
+}
+
+#[test]
+fn test_sexpr_escapes_string_literals() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        assert_eq(a, \"a (b)\");
+        return a;
+    }
+}",
+        );
+
+        let sexpr = to_sexpr(&frontend.program);
+        // The string is quoted, so its spaces and parentheses do not delimit atoms.
+        assert!(sexpr.contains(r#"(assert-eq a "a (b)")"#), "{sexpr}");
+    })
+}
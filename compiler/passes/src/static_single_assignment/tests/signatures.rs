@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, Frontend};
+use crate::{FunctionSignature, SsaOptions};
+
+use leo_ast::{IntegerType, Mode, ProgramConsumer, Type};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(public a: u8, b: u16) -> (u8, u16) {
+        let c: u8 = a + 1u8;
+        return (c, b) then finalize(a);
+    }
+
+    finalize main(a: u8) {
+        assert_eq(a, a);
+    }
+
+    function helper(constant a: u8) -> u8 {
+        return a;
+    }
+}";
+
+#[test]
+fn test_signatures_match_the_source() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        // Each collected signature matches the declaration of its function.
+        for name in ["main", "helper"] {
+            let source = function(&frontend.program, name);
+            let signature = assigner.signature(Symbol::intern(name)).unwrap();
+            assert_eq!(*signature, FunctionSignature::new(&source.input, &source.output, &source.output_type));
+        }
+
+        let (u8, u16) = (Type::Integer(IntegerType::U8), Type::Integer(IntegerType::U16));
+        let main = assigner.signature(Symbol::intern("main")).unwrap();
+        assert_eq!(main.inputs, [
+            (Symbol::intern("a"), Mode::Public, u8.clone()),
+            (Symbol::intern("b"), Mode::None, u16)
+        ]);
+        assert_eq!(main.outputs.len(), 2);
+        let helper = assigner.signature(Symbol::intern("helper")).unwrap();
+        assert_eq!(helper.inputs, [(Symbol::intern("a"), Mode::Constant, u8.clone())]);
+        assert_eq!(helper.output_type, u8);
+    })
+}
+
+#[test]
+fn test_finalize_signatures_are_collected_separately() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        let finalize = assigner.finalize_signature(Symbol::intern("main")).unwrap();
+        assert_eq!(finalize.inputs, [(Symbol::intern("a"), Mode::None, Type::Integer(IntegerType::U8))]);
+        assert!(finalize.outputs.is_empty());
+        assert_eq!(assigner.finalize_signature(Symbol::intern("helper")), None);
+    })
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{ImportLevelSummary, SsaOptions, SsaSummary, SsaSummaryDiff};
+
+use leo_ast::Program;
+use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a + b;
+        if c == 0u8 {
+            c = 1u8;
+        }
+        return c;
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}";
+
+#[test]
+fn test_summary_of_small_program() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let (_, summary) =
+            frontend.assigner(SsaOptions::default()).consume_program_with_summary(frontend.program.clone());
+
+        // `a + b`, `0u8`, `c == 0u8`, `1u8`, and the phi function for `c` each introduce a temporary.
+        assert_eq!(summary, SsaSummary {
+            functions: 2,
+            records_reordered: 1,
+            temporaries: 5,
+            imports: 0,
+            peak_scope_depth: 2,
+            peak_expression_depth: 1,
+            import_levels: vec![ImportLevelSummary { programs: 1, functions: 2, temporaries: 5, records: 1 }]
+        });
+    })
+}
+
+#[test]
+fn test_records_left_in_source_order_are_not_counted_as_reordered() {
+    create_session_if_not_set_then(|_| {
+        // The members are kept in source order, so the record is not reordered.
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions { preserve_member_order: true, ..Default::default() };
+        let (_, summary) = frontend.assigner(options).consume_program_with_summary(frontend.program.clone());
+        assert_eq!(summary.records_reordered, 0);
+
+        // The pass stops at the record, which is left unchanged.
+        let frontend = Frontend::new(PROGRAM);
+        let options = SsaOptions { abort_on_reordering: true, ..Default::default() };
+        let (_, summary) = frontend.assigner(options).consume_program_with_summary(frontend.program.clone());
+        assert_eq!(frontend.errors().len(), 1);
+        assert_eq!(summary.records_reordered, 0);
+    })
+}
+
+#[test]
+fn test_summary_diff() {
+    let before = SsaSummary {
+        functions: 2,
+        records_reordered: 1,
+        temporaries: 5,
+        imports: 0,
+        peak_scope_depth: 2,
+        peak_expression_depth: 1,
+        import_levels: Vec::new(),
+    };
+    let after = SsaSummary {
+        functions: 3,
+        records_reordered: 0,
+        temporaries: 3,
+        imports: 0,
+        peak_scope_depth: 2,
+        peak_expression_depth: 3,
+        import_levels: Vec::new(),
+    };
+
+    assert_eq!(before.diff(&after), SsaSummaryDiff {
+        functions: 1,
+        records_reordered: -1,
+        temporaries: -2,
+        imports: 0,
+        peak_scope_depth: 0,
+        peak_expression_depth: 2
+    });
+    assert_eq!(after.diff(&before).temporaries, 2);
+    assert!(before.diff(&before).is_empty());
+}
+
+#[test]
+fn test_deepest_expression_nesting_is_reported() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, b: u8, flag: bool) -> u8 {
+        let c: u8 = a + b;
+        if flag {
+            c = ((a + (b * (c - 1u8))) / 2u8) + c;
+        }
+        return c;
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let (_, summary) = assigner.consume_program_with_summary(frontend.program.clone());
+
+        // The nested expression in the then-block is five deep: `+`, `/`, `+`, `*`, `-`.
+        assert_eq!(assigner.nesting_depth(Symbol::intern("main")), Some(5));
+        assert_eq!(assigner.nesting_depth(Symbol::intern("helper")), Some(0));
+        assert_eq!(summary.peak_expression_depth, 5);
+    })
+}
+
+#[test]
+fn test_summary_groups_metrics_by_import_depth() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        // Returns a copy of the program scope of `test`, named `name`, that imports `import`, if any.
+        let copy = |name: &str, import: Option<(&str, Program)>| {
+            let mut program = frontend.program.clone();
+            program.program_scopes.values_mut().for_each(|scope| scope.program_id.name.name = Symbol::intern(name));
+            program.imports =
+                import.into_iter().map(|(name, import)| (Symbol::intern(name), (import, Span::default()))).collect();
+            program
+        };
+        // `test` imports `bank` and `math`, and `bank` imports `token`.
+        let mut program = frontend.program.clone();
+        program.imports = [
+            (Symbol::intern("bank"), (copy("bank", Some(("token", copy("token", None)))), Span::default())),
+            (Symbol::intern("math"), (copy("math", None), Span::default())),
+        ]
+        .into_iter()
+        .collect();
+
+        let (_, summary) = frontend.assigner(SsaOptions::default()).consume_program_with_summary(program);
+
+        // Each copy contributes two functions, five temporaries, and one record to the level at which it is imported.
+        let level = |programs: usize| ImportLevelSummary {
+            programs,
+            functions: 2 * programs,
+            temporaries: 5 * programs,
+            records: programs,
+        };
+        assert_eq!(summary.import_levels, [level(1), level(2), level(1)]);
+        assert_eq!(summary.imports, 3);
+        assert_eq!(summary.functions, 8);
+        assert_eq!(summary.temporaries, 20);
+    })
+}
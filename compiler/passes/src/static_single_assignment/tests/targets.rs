@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, Frontend};
+use crate::{SsaOptions, TargetFilter};
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    @testnet
+    transition on_testnet(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        return b;
+    }
+
+    @mainnet
+    transition on_mainnet(a: u8) -> u8 {
+        let b: u8 = a * 2u8;
+        return b;
+    }
+
+    transition everywhere(a: u8) -> u8 {
+        let b: u8 = a - 1u8;
+        return b;
+    }
+}";
+
+#[test]
+fn test_functions_for_inactive_targets_are_left_untouched() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let filter =
+            TargetFilter::new([Symbol::intern("testnet"), Symbol::intern("mainnet")], [Symbol::intern("testnet")]);
+        let options = SsaOptions { target_filter: Some(filter), ..Default::default() };
+        let program = frontend.assigner(options).consume_program(frontend.program.clone());
+
+        // Note that functions are compared by their blocks, since `Function` only compares identifiers.
+        assert_eq!(
+            function(&program, "on_mainnet").block.to_string(),
+            function(&frontend.program, "on_mainnet").block.to_string()
+        );
+        assert!(function(&program, "on_testnet").block.to_string().contains("b$"));
+        // Functions without a target annotation are compiled for every target.
+        assert!(function(&program, "everywhere").block.to_string().contains("b$"));
+    })
+}
+
+#[test]
+fn test_all_functions_are_consumed_without_a_filter() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+
+        for name in ["on_testnet", "on_mainnet", "everywhere"] {
+            assert!(function(&program, name).block.to_string().contains("b$"), "{name}");
+        }
+    })
+}
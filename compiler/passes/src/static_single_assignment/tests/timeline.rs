@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = a;
+        }
+        return b;
+    }
+}";
+
+/// Returns `renamings` as pairs of strings.
+fn renamings(renamings: &[(Symbol, Symbol)]) -> Vec<(String, String)> {
+    renamings.iter().map(|(old, new)| (old.to_string(), new.to_string())).collect()
+}
+
+#[test]
+fn test_timeline_records_renamings_per_statement() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions { record_timeline: true, ..Default::default() });
+        assigner.consume_program(frontend.program.clone());
+
+        // The entries are `let b`, `b = a` in the then-block, the conditional, and `return b`.
+        let timeline = assigner.timeline();
+        assert_eq!(timeline.len(), 4);
+        assert_eq!(renamings(&timeline[0].renamings), [("b".to_string(), "b$0".to_string())]);
+        assert_eq!(renamings(&timeline[1].renamings), [("b".to_string(), "b$1".to_string())]);
+        // The conditional introduces the phi function for `b`, but not the renaming of its then-block.
+        assert_eq!(renamings(&timeline[2].renamings), [("b".to_string(), "b$2".to_string())]);
+        assert!(timeline[3].renamings.is_empty());
+    })
+}
+
+#[test]
+fn test_timeline_is_off_by_default() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.consume_program(frontend.program.clone());
+
+        assert!(assigner.timeline().is_empty());
+    })
+}
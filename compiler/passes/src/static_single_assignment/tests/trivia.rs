@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{function, Frontend};
+use crate::SsaOptions;
+
+use leo_ast::ProgramConsumer;
+use leo_span::symbol::{create_session_if_not_set_then, with_session_globals};
+
+#[test]
+fn test_commented_function_keeps_its_span() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    /* Doubles `a`. */
+    @program
+    transition main(a: u8) -> u8 {
+        // The doubled input.
+        let b: u8 = a * 2u8;
+        return b; // The result.
+    }
+}",
+        );
+        let program = frontend.assigner(SsaOptions::default()).consume_program(frontend.program.clone());
+        let (source, output) = (function(&frontend.program, "main"), function(&program, "main"));
+
+        // The annotations, spans, and IDs of the function and its block survive the pass.
+        assert_eq!(output.annotations, source.annotations);
+        assert_eq!((output.span, output.id), (source.span, source.id));
+        assert_eq!((output.block.span, output.block.id), (source.block.span, source.block.id));
+
+        // The comments in the body can be recovered from the source spanned by the transformed function.
+        let contents = with_session_globals(|s| s.source_map.contents_of_span(output.block.span)).unwrap();
+        assert!(contents.contains("// The doubled input."), "{contents}");
+        assert!(contents.contains("return b; // The result."), "{contents}");
+    })
+}
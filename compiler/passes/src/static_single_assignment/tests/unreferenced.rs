@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{SsaOptions, UnreferencedStructPolicy};
+
+use leo_ast::{Program, ProgramConsumer};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    struct Line {
+        start: Point,
+        end: Point,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    record Unused {
+        owner: address,
+        data: u8,
+    }
+
+    transition mint(owner: address, amount: u64) -> Token {
+        return Token { owner, amount };
+    }
+
+    transition line(p: Point) -> u8 {
+        let l: Line = Line { start: p, end: p };
+        return l.end.x;
+    }
+
+    function origin(a: u8) -> u8 {
+        return a;
+    }
+}";
+
+/// Returns the names of the structs in the program scope of `program`.
+fn struct_names(program: &Program) -> Vec<String> {
+    let scope = program.program_scopes.values().next().unwrap();
+    scope.structs.iter().map(|(name, _)| name.to_string()).collect()
+}
+
+/// Consumes `PROGRAM` with `policy`, returning the output along with the names of the unreferenced structs.
+fn consume(policy: UnreferencedStructPolicy) -> (Program, Vec<String>) {
+    let frontend = Frontend::new(PROGRAM);
+    let mut assigner = frontend.assigner(SsaOptions { unreferenced_structs: policy, ..Default::default() });
+    let program = assigner.consume_program(frontend.program.clone());
+    let unreferenced = assigner
+        .unreferenced_structs(Symbol::intern("test"))
+        .expect("The program scope should be recorded.")
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    assert!(frontend.errors().is_empty());
+    (program, unreferenced)
+}
+
+#[test]
+fn test_unreferenced_structs_are_detected_and_kept_by_default() {
+    create_session_if_not_set_then(|_| {
+        let (program, unreferenced) = consume(UnreferencedStructPolicy::default());
+
+        // `Point` is referenced by an input and `Line` by a struct expression, but nothing references `Unused`.
+        assert_eq!(unreferenced, ["Unused"]);
+        assert_eq!(struct_names(&program), ["Point", "Line", "Token", "Unused"]);
+    })
+}
+
+#[test]
+fn test_unreferenced_structs_are_pruned() {
+    create_session_if_not_set_then(|_| {
+        let (program, unreferenced) = consume(UnreferencedStructPolicy::Prune);
+
+        assert_eq!(unreferenced, ["Unused"]);
+        assert_eq!(struct_names(&program), ["Point", "Line", "Token"]);
+    })
+}
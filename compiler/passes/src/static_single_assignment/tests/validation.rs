@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Frontend;
+use crate::{SsaOptions, SsaViolation};
+
+use leo_ast::ProgramConsumer;
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: address,
+    }
+
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = a + 1u8;
+        }
+        b = b * 2u8;
+        return b;
+    }
+}";
+
+#[test]
+fn test_output_of_the_pass_is_valid() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        assert_eq!(assigner.validate(&program), []);
+    })
+}
+
+#[test]
+fn test_source_program_violates_ssa_form() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let assigner = frontend.assigner(SsaOptions::default());
+
+        // `b` is reassigned in the then-block and after the conditional, and `Token` does not start with `owner`.
+        let violations = assigner.validate(&frontend.program);
+        let descriptions: Vec<String> = violations.iter().map(|violation| violation.to_string()).collect();
+        assert_eq!(descriptions, [
+            "the members of `Token` are not in the order given by the record layout",
+            "`b` is assigned more than once in `main`",
+            "`b` is assigned more than once in `main`",
+        ]);
+        assert!(matches!(violations[1], SsaViolation::MultipleAssignment { name, .. } if name == Symbol::intern("b")));
+    })
+}
+
+#[test]
+fn test_use_before_definition() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = c;
+        let c: u8 = a;
+        return b;
+    }
+}",
+        );
+        let assigner = frontend.assigner(SsaOptions::default());
+
+        // The input `a` is assigned on entry, while `c` is used before it is assigned.
+        let violations = assigner.validate(&frontend.program);
+        assert_eq!(violations.len(), 1);
+        assert!(
+            matches!(violations[0], SsaViolation::UseBeforeDefinition { name, .. } if name == Symbol::intern("c")),
+            "{violations:?}"
+        );
+    })
+}
+
+#[test]
+fn test_assignment_in_one_branch_does_not_dominate() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        if c {
+            let d: u8 = a;
+            let g: u8 = a;
+        } else {
+            let e: u8 = d;
+        }
+        let f: u8 = g;
+        return f;
+    }
+}",
+        );
+        let assigner = frontend.assigner(SsaOptions::default());
+
+        // `d` is only assigned in the then-block, so its use in the otherwise-block is not dominated, nor is the use of `g` after the conditional.
+        let violations = assigner.validate(&frontend.program);
+        let names: Vec<Symbol> = violations
+            .iter()
+            .map(|violation| match violation {
+                SsaViolation::UseBeforeDefinition { name, .. } => *name,
+                violation => panic!("Expected a use before definition, found `{violation}`."),
+            })
+            .collect();
+        assert_eq!(names, [Symbol::intern("d"), Symbol::intern("g")]);
+    })
+}
+
+#[test]
+fn test_phi_functions_of_both_branches_are_valid() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(
+            "
+program test.aleo {
+    transition main(a: u8, c: bool) -> u8 {
+        let b: u8 = a;
+        if c {
+            b = a + 1u8;
+        } else if a == 0u8 {
+            b = a + 2u8;
+        } else {
+            b = a + 3u8;
+        }
+        return b;
+    }
+}",
+        );
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        let program = assigner.consume_program(frontend.program.clone());
+
+        assert_eq!(assigner.validate(&program), []);
+    })
+}
+
+#[test]
+fn test_validation_does_not_mint_names() {
+    create_session_if_not_set_then(|_| {
+        let frontend = Frontend::new(PROGRAM);
+        let mut assigner = frontend.assigner(SsaOptions::default());
+        assigner.validate(&frontend.program);
+
+        // The first name minted by the pass is unaffected by the validation.
+        let program = assigner.consume_program(frontend.program.clone());
+        assert!(program.to_string().contains("b$0"), "{program}");
+    })
+}
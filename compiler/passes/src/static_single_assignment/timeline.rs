@@ -0,0 +1,30 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::NodeID;
+use leo_span::{Span, Symbol};
+
+/// The renamings introduced while consuming a single statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimelineEntry {
+    /// The ID of the consumed statement.
+    pub id: NodeID,
+    /// The span of the consumed statement.
+    pub span: Span,
+    /// The mappings from original names to new names introduced by the statement, in order.
+    /// Note that the renamings introduced by nested statements are recorded in their own entries.
+    pub renamings: Vec<(Symbol, Symbol)>,
+}
@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{RecordLayout, SsaOptions};
+
+use leo_ast::{
+    AssignStatement,
+    Block,
+    ConditionalStatement,
+    DefinitionStatement,
+    Expression,
+    ExpressionVisitor,
+    Function,
+    Identifier,
+    Input,
+    IterationStatement,
+    ProgramScope,
+    ProgramVisitor,
+    StatementVisitor,
+    Struct,
+    StructExpression,
+    TernaryExpression,
+};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt;
+
+/// A violation of the invariants of static single assignment form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SsaViolation {
+    /// A name is assigned more than once in the same function or finalize block.
+    MultipleAssignment { function: Symbol, name: Symbol, span: Span },
+    /// A name is used before it is assigned in the same function or finalize block.
+    UseBeforeDefinition { function: Symbol, name: Symbol, span: Span },
+    /// The members of a record are not in the order given by the record layout of its program scope.
+    NonCanonicalRecord { record: Symbol, span: Span },
+}
+
+impl fmt::Display for SsaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MultipleAssignment { function, name, .. } => {
+                write!(f, "`{name}` is assigned more than once in `{function}`")
+            }
+            Self::UseBeforeDefinition { function, name, .. } => {
+                write!(f, "`{name}` is used before it is assigned in `{function}`")
+            }
+            Self::NonCanonicalRecord { record, .. } => {
+                write!(f, "the members of `{record}` are not in the order given by the record layout")
+            }
+        }
+    }
+}
+
+/// A read-only traversal that checks whether a program is in static single assignment form.
+/// A use is dominated by the assignments that precede it in its block and the enclosing blocks, so an assignment in one branch of
+/// a conditional statement does not dominate the other branch, and only the names assigned in both branches dominate the statements after it.
+/// The phi functions following a conditional statement are the exception, since they merge the names assigned in either branch.
+pub(crate) struct SsaValidator<'a> {
+    /// The options giving the record layout of each program scope.
+    options: &'a SsaOptions,
+    /// The record layout of the program scope currently being validated.
+    record_layout: RecordLayout,
+    /// The name of the function currently being validated.
+    function: Symbol,
+    /// The names assigned thus far in the function or finalize block currently being validated.
+    assigned: IndexSet<Symbol>,
+    /// The names whose assignments dominate the statement currently being validated.
+    defined: IndexSet<Symbol>,
+    /// The names assigned in only one branch of the preceding conditional statement, which its phi functions may use.
+    branch_names: IndexSet<Symbol>,
+    /// Whether the traversal is in the arguments of a phi function.
+    in_phi: bool,
+    /// The names used before they are assigned, mapped to the span of their first use.
+    pending: IndexMap<Symbol, Span>,
+    /// The violations found thus far.
+    pub(crate) violations: Vec<SsaViolation>,
+}
+
+impl<'a> SsaValidator<'a> {
+    /// Returns a new `SsaValidator` using the record layouts given by `options`.
+    pub(crate) fn new(options: &'a SsaOptions) -> Self {
+        Self {
+            options,
+            record_layout: options.record_layout.clone(),
+            function: Symbol::intern(""),
+            assigned: IndexSet::new(),
+            defined: IndexSet::new(),
+            branch_names: IndexSet::new(),
+            in_phi: false,
+            pending: IndexMap::new(),
+            violations: Vec::new(),
+        }
+    }
+
+    /// Validates a function or finalize block with the given `inputs` and `block`.
+    /// A use of a name that is assigned in the block, but not by an assignment dominating the use, is reported once the block is validated.
+    fn validate_block(&mut self, inputs: &[Input], block: &Block) {
+        self.defined = inputs.iter().map(|input| input.identifier().name).collect();
+        self.assigned = self.defined.clone();
+        self.branch_names.clear();
+        self.pending.clear();
+        self.visit_block(block);
+        for (name, span) in core::mem::take(&mut self.pending) {
+            if self.assigned.contains(&name) {
+                self.violations.push(SsaViolation::UseBeforeDefinition { function: self.function, name, span });
+            }
+        }
+    }
+
+    /// Records the assignment of `identifier`.
+    fn define(&mut self, identifier: &Identifier) {
+        self.defined.insert(identifier.name);
+        if !self.assigned.insert(identifier.name) {
+            self.violations.push(SsaViolation::MultipleAssignment {
+                function: self.function,
+                name: identifier.name,
+                span: identifier.span,
+            });
+        }
+        // A use that precedes the assignment is not dominated by it.
+        if let Some(span) = self.pending.shift_remove(&identifier.name) {
+            self.violations.push(SsaViolation::UseBeforeDefinition {
+                function: self.function,
+                name: identifier.name,
+                span,
+            });
+        }
+    }
+
+    /// Records the assignment of each name in `place`.
+    fn define_place(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(identifier) => self.define(identifier),
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.define_place(element)),
+            // Other places do not assign a name.
+            _ => {}
+        }
+    }
+
+    /// Validates the value of an assignment or definition, ending the phi functions of the preceding conditional statement,
+    /// unless the value is a phi function or the copy of one into the variable it merges.
+    fn visit_value(&mut self, value: &Expression) {
+        if !matches!(value, Expression::Ternary(_) | Expression::Identifier(_)) {
+            self.branch_names.clear();
+        }
+        self.visit_expression(value, &());
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for SsaValidator<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    /// Records the use of `input`.
+    /// Note that a name that is never assigned, e.g. an input or a mapping, is not a violation.
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        let is_merged = self.in_phi && self.branch_names.contains(&input.name);
+        if !self.defined.contains(&input.name) && !is_merged {
+            self.pending.entry(input.name).or_insert(input.span);
+        }
+    }
+
+    /// A ternary expression selecting between two names is a phi function, whose arguments may be assigned in either branch.
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.condition, additional);
+        self.in_phi =
+            matches!((&*input.if_true, &*input.if_false), (Expression::Identifier(_), Expression::Identifier(_)));
+        self.visit_expression(&input.if_true, additional);
+        self.visit_expression(&input.if_false, additional);
+        self.in_phi = false;
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A member without an expression uses the name of the member.
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for SsaValidator<'_> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_value(&input.value);
+        self.define_place(&input.place);
+    }
+
+    /// Validates each branch with the names whose assignments dominate the conditional statement.
+    /// The names assigned in both branches dominate the statements after it, and those assigned in one branch may be merged by its phi functions.
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.branch_names.clear();
+        self.visit_expression(&input.condition, &());
+        let before = self.defined.clone();
+        self.visit_block(&input.then);
+        let then = core::mem::replace(&mut self.defined, before.clone());
+        if let Some(otherwise) = input.otherwise.as_ref() {
+            self.visit_statement(otherwise);
+        }
+        let otherwise = core::mem::replace(&mut self.defined, before);
+        self.branch_names = then.symmetric_difference(&otherwise).copied().collect();
+        self.defined = then.intersection(&otherwise).copied().collect();
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_value(&input.value);
+        self.define_place(&input.place);
+    }
+
+    /// Validates the block of the loop, whose assignments do not dominate the statements after the loop.
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        let before = self.defined.clone();
+        self.define(&input.variable);
+        self.visit_block(&input.block);
+        self.defined = before;
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for SsaValidator<'_> {
+    /// Validates the structs and functions of the program scope, using the record layout of the program scope.
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.record_layout = self.options.layout_of(&input.program_id);
+        input.structs.iter().for_each(|(_, struct_)| self.visit_struct(struct_));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        if input.is_record && !self.options.preserve_member_order && !self.record_layout.is_canonical(&input.members) {
+            self.violations.push(SsaViolation::NonCanonicalRecord { record: input.identifier.name, span: input.span });
+        }
+    }
+
+    /// Validates the block and finalize block of the function, each of which is in its own scope.
+    fn visit_function(&mut self, input: &'a Function) {
+        self.function = input.identifier.name;
+        self.validate_block(&input.input, &input.block);
+        if let Some(finalize) = &input.finalize {
+            self.validate_block(&finalize.input, &finalize.block);
+        }
+    }
+}
@@ -266,6 +266,7 @@ symbols! {
     public,
     private,
     owner,
+    gates,
     _nonce,
     program,
     block,
@@ -36,6 +36,8 @@ use std::fmt;
 pub struct Formatted {
     /// The formatted error span information.
     pub span: Span,
+    /// The secondary spans of the error, each with a message displayed beneath it.
+    pub labels: Vec<(Span, String)>,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: Backtraced,
 }
@@ -58,6 +60,7 @@ impl Formatted {
     {
         Self {
             span,
+            labels: Vec::new(),
             backtrace: Backtraced::new_from_backtrace(
                 message.to_string(),
                 help,
@@ -70,6 +73,12 @@ impl Formatted {
         }
     }
 
+    /// Attaches a secondary `span`, displayed beneath the primary span with `message`.
+    pub fn with_label<S: ToString>(mut self, span: Span, message: S) -> Self {
+        self.labels.push((span, message.to_string()));
+        self
+    }
+
     /// Calls the backtraces error exit code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -86,36 +95,67 @@ impl Formatted {
     }
 }
 
-impl fmt::Display for Formatted {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
+/// Writes the location and source lines of `span`, underlined and followed by `label` if it is given.
+fn write_snippet(f: &mut fmt::Formatter, span: Span, label: Option<&str>) -> fmt::Result {
+    let underline = |mut start: usize, mut end: usize| -> String {
+        if start > end {
+            std::mem::swap(&mut start, &mut end)
+        }
 
-            let mut underline = String::new();
+        let mut underline = String::new();
 
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
-            }
+        for _ in 0..start {
+            underline.push(' ');
+            end -= 1;
+        }
 
-            for _ in 0..end {
-                underline.push('^');
-            }
+        for _ in 0..end {
+            underline.push('^');
+        }
 
-            underline
-        };
+        underline
+    };
+
+    let (loc, contents) = with_session_globals(|s| {
+        (
+            s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy),
+            s.source_map.line_contents_of_span(span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
+        )
+    });
+
+    let underlined = underline(loc.col_start, loc.col_stop);
+
+    write!(
+        f,
+        "\n{indent     }--> {path}:{line_start}:{start}\n\
+        {indent     } |\n",
+        indent = INDENT,
+        path = &loc.source_file.name,
+        line_start = loc.line_start,
+        start = loc.col_start,
+    )?;
+
+    for (line_no, line) in contents.lines().enumerate() {
+        writeln!(
+            f,
+            "{line_no:width$} | {text}",
+            width = INDENT.len(),
+            line_no = loc.line_start + line_no,
+            text = line,
+        )?;
+    }
+
+    write!(f, "{INDENT     } |{underlined}",)?;
 
-        let (loc, contents) = with_session_globals(|s| {
-            (
-                s.source_map.span_to_location(self.span).unwrap_or_else(SpanLocation::dummy),
-                s.source_map.line_contents_of_span(self.span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
-            )
-        });
+    if let Some(label) = label {
+        write!(f, " {label}")?;
+    }
 
-        let underlined = underline(loc.col_start, loc.col_stop);
+    Ok(())
+}
 
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let (kind, code) =
             if self.backtrace.error { ("Error", self.error_code()) } else { ("Warning", self.warning_code()) };
 
@@ -132,28 +172,13 @@ impl fmt::Display for Formatted {
             write!(f, "{message}")?;
         };
 
-        write!(
-            f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
-            indent = INDENT,
-            path = &loc.source_file.name,
-            line_start = loc.line_start,
-            start = loc.col_start,
-        )?;
+        write_snippet(f, self.span, None)?;
 
-        for (line_no, line) in contents.lines().enumerate() {
-            writeln!(
-                f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
-                text = line,
-            )?;
+        for (span, label) in self.labels.iter() {
+            write!(f, "\n{INDENT     } |")?;
+            write_snippet(f, *span, Some(label))?;
         }
 
-        write!(f, "{INDENT     } |{underlined}",)?;
-
         if let Some(help) = &self.backtrace.help {
             write!(
                 f,
@@ -88,6 +88,15 @@ macro_rules! create_messages {
 
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
+            /// Attaches a secondary `span` to a formatted message, displayed beneath its primary span with `message`.
+            /// Backtraced messages have no span, so they are returned unchanged.
+            pub fn with_label(self, span: leo_span::Span, message: impl ToString) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_label(span, message)),
+                    backtraced => backtraced,
+                }
+            }
+
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
         }
     };
@@ -48,6 +48,9 @@ pub use self::package::*;
 pub mod parser;
 pub use self::parser::*;
 
+pub mod static_single_assigner;
+pub use self::static_single_assigner::*;
+
 /// Contains the Type Checker error definitions.
 pub mod type_checker;
 
@@ -84,6 +87,9 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents a Static Single Assigner Error in a Leo Error.
+    #[error(transparent)]
+    StaticSingleAssignerError(#[from] StaticSingleAssignerError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -108,6 +114,7 @@ impl LeoError {
             TypeCheckerError(error) => error.error_code(),
             LoopUnrollerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            StaticSingleAssignerError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
@@ -127,6 +134,7 @@ impl LeoError {
             TypeCheckerError(error) => error.exit_code(),
             LoopUnrollerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            StaticSingleAssignerError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
@@ -140,6 +148,9 @@ pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Static Single Assigner Warning in a Leo Warning.
+    #[error(transparent)]
+    StaticSingleAssignerWarning(#[from] StaticSingleAssignerWarning),
 }
 
 impl LeoWarning {
@@ -149,6 +160,7 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            StaticSingleAssignerWarning(warning) => warning.warning_code(),
         }
     }
 }
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// StaticSingleAssignerError enum that represents all the errors for the static single assignment pass in the `leo-passes` crate.
+    StaticSingleAssignerError,
+    code_mask: 8000i32,
+    code_prefix: "SSA",
+
+    /// For when a record does not declare one of the reserved fields of its layout.
+    @formatted
+    missing_reserved_field {
+        args: (record: impl Display, field: impl Display),
+        msg: format!("The record `{record}` does not have the reserved field `{field}`."),
+        help: Some(format!("Add a `{field}` field to `{record}`.")),
+    }
+
+    /// For when the pass introduces more temporaries than allowed by its budget.
+    @formatted
+    temporaries_budget_exceeded {
+        args: (function: impl Display, budget: impl Display),
+        msg: format!("The function `{function}` exceeds the budget of {budget} temporaries per function."),
+        help: Some("This is usually caused by very large or deeply nested expressions. Consider splitting up the function.".to_string()),
+    }
+
+    /// For when a record does not declare the reserved `owner` field.
+    @formatted
+    missing_owner_field {
+        args: (record: impl Display),
+        msg: format!("The record `{record}` does not have an `owner` field."),
+        help: Some(format!("Add an `owner: address` field to `{record}`.")),
+    }
+
+    /// For when a record does not declare the reserved `gates` field.
+    @formatted
+    missing_gates_field {
+        args: (record: impl Display),
+        msg: format!("The record `{record}` does not have a `gates` field."),
+        help: Some(format!("Add a `gates: u64` field to `{record}`.")),
+    }
+
+    /// For when a constant input is reassigned.
+    @formatted
+    constant_input_reassigned {
+        args: (name: impl Display),
+        msg: format!("The constant input `{name}` is reassigned."),
+        help: Some(format!("Constant inputs cannot be reassigned. Consider defining a new variable from `{name}` instead.")),
+    }
+
+    /// For when a name introduced by the pass collides with a name in the source.
+    @formatted
+    introduced_name_collides {
+        args: (name: impl Display),
+        msg: format!("The name `{name}` introduced by the pass collides with a variable of the same name."),
+        help: Some(format!("Rename `{name}`, since it has the form of the names introduced by the pass, e.g. `a__3`.")),
+    }
+
+    /// For when a variable is used before it is defined.
+    @formatted
+    use_before_definition {
+        args: (name: impl Display),
+        msg: format!("The variable `{name}` is used before it is defined."),
+        help: Some(format!("Define `{name}` in an enclosing block before using it.")),
+    }
+
+    /// For when the field name transformation maps two fields of a record to the same name.
+    @formatted
+    transformed_field_names_collide {
+        args: (record: impl Display, first: impl Display, second: impl Display, name: impl Display),
+        msg: format!("The fields `{first}` and `{second}` of the record `{record}` are both transformed into `{name}`."),
+        help: Some(format!("Rename `{first}` or `{second}`, so that their transformed names are distinct. The fields of `{record}` are left unchanged.")),
+    }
+
+    /// For when the pass exceeds its deadline.
+    @formatted
+    deadline_exceeded {
+        args: (function: impl Display),
+        msg: format!("The pass exceeded its deadline before consuming the function `{function}`."),
+        help: Some("The remaining functions are left unchanged. Consider raising the deadline or splitting up the program.".to_string()),
+    }
+
+    /// For when the fields of a record are not in canonical order, and strict records are requested.
+    @formatted
+    record_not_canonical {
+        args: (record: impl Display, order: impl Display),
+        msg: format!("The fields of the record `{record}` are not in canonical order."),
+        help: Some(format!("Declare the fields of `{record}` in the order `{order}`.")),
+    }
+
+    /// For when the rename hook chooses a name that is already in use.
+    @formatted
+    chosen_name_not_unique {
+        args: (name: impl Display, original: impl Display),
+        msg: format!("The name `{name}` chosen for `{original}` by the rename hook is already in use."),
+        help: Some(format!("Only pin a name to a variable that is assigned once. The name proposed by the pass is used for `{original}` instead.")),
+    }
+
+    /// For when a program imports itself.
+    @formatted
+    program_imports_itself {
+        args: (program: impl Display),
+        msg: format!("The program `{program}` imports itself."),
+        help: Some(format!("Remove the import of `{program}` from `{program}`.")),
+    }
+
+    /// For when a definition in a function body reuses the name of a pinned input.
+    @formatted
+    pinned_input_redefined {
+        args: (name: impl Display),
+        msg: format!("The definition of `{name}` reuses the name of an input, which is pinned."),
+        help: Some(format!("The names of the inputs are kept as in the source. Consider renaming the variable, or assigning to `{name}` instead.")),
+    }
+
+    /// For when `self` is referenced other than as `self.caller` or `self.signer`.
+    @formatted
+    unsupported_receiver {
+        args: (),
+        msg: "`self` is referenced as a value, but functions have no implicit receiver.".to_string(),
+        help: Some("Methods on records are not supported. Consider passing the record as an input, or using `self.caller` or `self.signer`.".to_string()),
+    }
+
+    /// For when a program and its imports declare more functions than allowed.
+    @formatted
+    function_limit_exceeded {
+        args: (count: impl Display, max: impl Display),
+        msg: format!("The program declares {count} functions, including its imports, which exceeds the limit of {max}."),
+        help: Some("Consider splitting up the program, or raising the limit on the number of functions.".to_string()),
+    }
+
+    /// For when the collision checker rejects every new name proposed for a variable.
+    @formatted
+    new_names_rejected {
+        args: (name: impl Display, attempts: impl Display),
+        msg: format!("The collision checker rejected all {attempts} new names proposed for `{name}`."),
+        help: Some(format!("The last name proposed for `{name}` is used instead. Consider relaxing the collision checker.")),
+    }
+
+    /// For when the rename table exceeds the configured number of entries.
+    @formatted
+    rename_table_limit_exceeded {
+        args: (function: impl Display, max: impl Display),
+        msg: format!("The function `{function}` exceeds the limit of {max} entries in the rename table."),
+        help: Some("The remaining functions are left unchanged. Consider splitting up the function, or raising the limit.".to_string()),
+    }
+
+    /// For when a record declares one of the reserved fields of its layout more than once.
+    @formatted
+    duplicate_reserved_field {
+        args: (record: impl Display, field: impl Display),
+        msg: format!("The record `{record}` declares the reserved field `{field}` more than once."),
+        help: Some(format!("Remove all but one `{field}` field from `{record}`.")),
+    }
+
+    /// For when an expression has no type, since the program has not been type checked.
+    @formatted
+    untyped_expression {
+        args: (),
+        msg: "The expression has no type.".to_string(),
+        help: Some("The pass expects a type checked program. Run type checking before this pass.".to_string()),
+    }
+
+    /// For when the struct rewriter adds a member to a struct, which an initializer of the struct does not initialize.
+    @formatted
+    rewritten_member_uninitialized {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("The member `{member}` added to `{struct_}` by the struct rewriter is not initialized."),
+        help: Some(format!("Give the struct rewriter a default initializer of `{member}` with `StructRewriter::with_default`.")),
+    }
+);
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// StaticSingleAssignerWarning enum that represents all the warnings for the static single assignment pass in the `leo-passes` crate.
+    StaticSingleAssignerWarning,
+    code_mask: 8000i32,
+    code_prefix: "SSA",
+
+    /// For when a variable defined in a function body shadows one of the function's inputs.
+    @formatted
+    input_shadowed_by_definition {
+        args: (name: impl Display),
+        msg: format!("The definition of `{name}` shadows an input."),
+        help: Some("Shadowing an input is often unintentional. Consider renaming the variable.".to_string()),
+    }
+
+    /// For when a field of a record resembles a reserved field, but is not hoisted.
+    @formatted
+    field_resembles_reserved_field {
+        args: (record: impl Display, field: impl Display, reserved: impl Display),
+        msg: format!("The field `{field}` of the record `{record}` resembles the reserved field `{reserved}`, but is not reserved."),
+        help: Some(format!("Only a field named exactly `{reserved}` is treated as reserved. Consider renaming `{field}` to avoid confusion.")),
+    }
+
+    /// For when the pass expands the statements of a function by more than the configured ratio.
+    @formatted
+    statement_expansion_exceeded {
+        args: (function: impl Display, input: impl Display, output: impl Display, threshold: impl Display),
+        msg: format!("The function `{function}` expands from {input} to {output} statements, which exceeds the ratio of {threshold}."),
+        help: Some("This is usually caused by complex expressions or deeply nested conditionals. Consider simplifying the function.".to_string()),
+    }
+
+    /// For when the `owner` field of a record is not declared in the position given by the record layout.
+    @formatted
+    misplaced_owner_field {
+        args: (record: impl Display, expected: impl Display, actual: impl Display),
+        msg: format!("The `owner` field of the record `{record}` is declared at position {actual}, rather than position {expected}."),
+        help: Some(format!("The `owner` field is moved to position {expected}. Consider declaring it there, so that the source matches the record encoding.")),
+    }
+
+    /// For when the `gates` field of a record is not declared in the position given by the record layout.
+    @formatted
+    misplaced_gates_field {
+        args: (record: impl Display, expected: impl Display, actual: impl Display),
+        msg: format!("The `gates` field of the record `{record}` is declared at position {actual}, rather than position {expected}."),
+        help: Some(format!("The `gates` field is moved to position {expected}, after the reserved fields that precede it in the layout. Consider declaring it there.")),
+    }
+
+    /// For when a record is expected to be normalized by a prior pass, but still needs reordering.
+    @formatted
+    record_not_normalized {
+        args: (record: impl Display, order: impl Display),
+        msg: format!("The fields of the record `{record}` were expected to be normalized by a prior pass, but needed reordering."),
+        help: Some(format!("The fields are reordered to `{order}`. Check that the normalization pass runs before this pass.")),
+    }
+
+    /// For when a record omits a reserved field that the record layout allows to be omitted.
+    @formatted
+    optional_field_absent {
+        args: (record: impl Display, field: impl Display),
+        msg: format!("The record `{record}` does not declare the optional field `{field}`."),
+        help: Some(format!("The remaining fields are ordered as if `{field}` were absent from the layout. This is only a note for the migration of records.")),
+    }
+
+    /// For when a function introduces more temporaries than the configured threshold.
+    @formatted
+    temporary_threshold_exceeded {
+        args: (function: impl Display, count: impl Display, threshold: impl Display),
+        msg: format!("The function `{function}` introduces {count} temporaries, which exceeds the threshold of {threshold}."),
+        help: Some("This indicates that the function may be too complex. Consider splitting up the function.".to_string()),
+    }
+);